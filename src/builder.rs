@@ -30,12 +30,13 @@ macro_rules! table {
 /// 
 /// If you input "-"s, it will generate [Content::Splitter].
 /// 
-/// If you input an expression, it will generate a new [Content::Text] by calling [Content::new]. You can also add alignment, padding and width definitions.
+/// If you input an expression, it will generate a new [Content::Text] by calling [Content::new]. You can also add alignment, padding, width and style definitions.
 /// 
 /// ```rust
 /// # use table_formatter::cell;
-/// # use table_formatter::table::{Align, Padding};
+/// # use table_formatter::table::{Align, Padding, Style};
 /// cell!("hello world", align=Align::Left, padding=Padding::NONE);
+/// cell!("hello world", align=Align::Right, style=Style::new().bold());
 /// ```
 /// 
 /// [Content::None]: ../table/enum.Content.html#variant.None
@@ -50,8 +51,8 @@ macro_rules! cell {
     ($(-)+) => {
         $crate::table::Cell::default().with_content($crate::table::Content::Splitter)
     };
-    ($d: expr $(, align=$align: expr)? $(, padding=$padding: expr)? $(, width=$width: expr)?) => {
-        $crate::table::Cell::default().with_content($crate::table::Content::new($d))$(.with_align($align))?$(.with_padding($padding))?$(.with_width($width))?
+    ($d: expr $(, align=$align: expr)? $(, padding=$padding: expr)? $(, width=$width: expr)? $(, style=$style: expr)?) => {
+        $crate::table::Cell::default().with_content($crate::table::Content::new($d))$(.with_align($align))?$(.with_padding($padding))?$(.with_width($width))?$(.with_style($style))?
     };
 }
 
@@ -59,7 +60,7 @@ macro_rules! cell {
 /// 
 /// Since the formatters use both closures and functions, and that two types are not capable with each other, the lib uses a enum(FormatterFunc) to combine them together.
 /// 
-/// This macro will automatically convert any function pointers and closures to a `Rc<Box<dyn Fn(...) -> ...>>`, generating [FormatterFunc::Boxed].
+/// This macro will automatically convert any function pointers and closures to a `Arc<dyn Fn(...) -> ... + Send + Sync>`, generating [FormatterFunc::Boxed].
 /// 
 /// You can just use it as the way you use the `vec!` macro.
 /// 
@@ -78,10 +79,10 @@ macro_rules! fmt {
         vec![]
     };
     ($func: expr) => {
-        vec![$crate::table::FormatterFunc::Boxed(std::rc::Rc::new(Box::new($func)))]
+        vec![$crate::table::FormatterFunc::Boxed(std::sync::Arc::new($func))]
     };
     ($($func: expr),+ $(,)?) => {
-        vec![$($crate::table::FormatterFunc::Boxed(std::rc::Rc::new(Box::new($func))), )*]
+        vec![$($crate::table::FormatterFunc::Boxed(std::sync::Arc::new($func)), )*]
     }
 }
 
@@ -1,6 +1,6 @@
 //! Error definitions.
 
-use std::io;
+use std::{fmt, io};
 
 use thiserror::Error;
 
@@ -21,8 +21,48 @@ pub enum TableError {
     },
     #[error("Merge overwriting cells: ({x} {y})")]
     MergeOverwrite { x: usize, y: usize },
+    #[error("Splitting merged cell: ({x} {y})")]
+    MergeSplit { x: usize, y: usize },
+    #[error("Sorting on merged cell: ({x} {y})")]
+    SortOnMerged { x: usize, y: usize },
+    #[error("Row out of table - row {row}: the table has {rows} rows")]
+    RowOutOfTable { row: usize, rows: usize },
+    #[error("Page out of table - page {page}: the table has {pages} pages")]
+    PageOutOfTable { page: usize, pages: usize },
+    #[error("Column out of table - column {column}: the table has {columns} columns")]
+    ColumnOutOfTable { column: usize, columns: usize },
+    #[error("Invalid column size - column {column}: find {actual} cells, expect {expected}")]
+    InvalidColumnSize {
+        column: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Table has no header - column {column}: a header cell was given")]
+    NoHeader { column: usize },
     #[error("Write to buffer failed: {0}")]
     WriteError(io::Error),
+    #[error("Write to formatter failed: {0}")]
+    FormatError(fmt::Error),
+    #[error("Read from input failed: {0}")]
+    ReadError(io::Error),
+    #[error("Invalid record length - line {line}: find {actual} fields, expect {expected}")]
+    InvalidRecordLength {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Unterminated quoted field starting on line {line}")]
+    UnterminatedQuote { line: usize },
+    #[error("Misplaced quote on line {line}")]
+    InvalidQuote { line: usize },
+    #[error("Invalid table text - line {line}: {reason}")]
+    InvalidTableText { line: usize, reason: String },
+    /// Only returned with the `json` feature, but always declared so that enabling it doesn't break matches.
+    #[error("Unsupported JSON input: {0}")]
+    JsonError(String),
+    /// Only returned with the `serde` feature, but always declared like [JsonError](#variant.JsonError).
+    #[error("Serializing a row failed: {0}")]
+    SerializeError(String),
     #[error("Unexpected Error: {0}; please report to the author")]
     Unexpected(String),
 }
@@ -32,3 +72,9 @@ impl From<io::Error> for TableError {
         Self::WriteError(value)
     }
 }
+
+impl From<fmt::Error> for TableError {
+    fn from(value: fmt::Error) -> Self {
+        Self::FormatError(value)
+    }
+}
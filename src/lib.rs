@@ -38,9 +38,7 @@
 //!     table_cells
 //!     with Border::ALL
 //! };
-//! let mut buffer = vec![];
-//! table.render(&mut buffer).unwrap();
-//! println!("{}", String::from_utf8(buffer).unwrap());
+//! println!("{}", table);
 //! ```
 //!
 //! ## Output Definition
@@ -1,7 +1,8 @@
 use colored::ColoredString;
 
-use crate::table::{Align, Content, Overflow, Padding};
+use crate::table::{Align, ColorDepth, Content, Overflow, Padding, Span, Style, StyleScope};
 
+use super::style::Decoration;
 use super::FormatterFunc;
 
 /// Basic item for rendering a table.
@@ -20,13 +21,19 @@ pub struct Cell {
     align: Align,
     padding: Padding,
     merge: Option<usize>,
+    style: Style,
+    style_scope: StyleScope,
+    link: Option<String>,
     formatter: Vec<FormatterFunc>,
 }
 
 impl Cell {
+    /// Set the content. Numeric [Content::Value]s also make the cell right-aligned, call [with_align] afterwards to change it.
+    ///
+    /// [Content::Value]: enum.Content.html#variant.Value
+    /// [with_align]: #method.with_align
     pub fn with_content(mut self, content: Content) -> Self {
-        self.width = content.get_width();
-        self.content = content;
+        self.set_content(content);
         self
     }
     pub fn with_overflow(mut self, overflow: Overflow) -> Self {
@@ -49,12 +56,31 @@ impl Cell {
         self.merge = merge;
         self
     }
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn with_style_scope(mut self, scope: StyleScope) -> Self {
+        self.style_scope = scope;
+        self
+    }
+    /// Make the content a hyperlink to `link`.
+    ///
+    /// The normal renderer emits an OSC 8 escape sequence, which is ignored by terminals without support for it.
+    /// The markdown renderer emits `[text](link)`, and the raw renderer only outputs the text.
+    pub fn with_link(mut self, link: Option<String>) -> Self {
+        self.link = link;
+        self
+    }
     pub fn with_formatter(mut self, formatter: Vec<FormatterFunc>) -> Self {
         self.formatter = formatter;
         self
     }
 
     pub fn set_content(&mut self, content: Content) {
+        if content.is_number() {
+            self.align = Align::Right;
+        }
         self.width = content.get_width();
         self.content = content;
     }
@@ -73,6 +99,15 @@ impl Cell {
     pub fn set_merge(&mut self, merge: Option<usize>) {
         self.merge = merge;
     }
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+    pub fn set_style_scope(&mut self, scope: StyleScope) {
+        self.style_scope = scope;
+    }
+    pub fn set_link(&mut self, link: Option<String>) {
+        self.link = link;
+    }
     pub fn set_formatter(mut self, formatter: Vec<FormatterFunc>) {
         self.formatter = formatter;
     }
@@ -100,7 +135,7 @@ impl Cell {
         self.set_merge(Some(span));
         self.set_width(None);
         let mut v = vec![self];
-        v.extend(std::iter::repeat(Self::default()).take(span));
+        v.extend(std::iter::repeat_n(Self::default(), span));
         v
     }
 
@@ -116,39 +151,163 @@ impl Cell {
     pub fn get_align(&self) -> Align {
         self.align
     }
+    pub fn get_style(&self) -> Style {
+        self.style
+    }
+    pub fn get_style_scope(&self) -> StyleScope {
+        self.style_scope
+    }
+    pub fn get_link(&self) -> Option<&String> {
+        self.link.as_ref()
+    }
 
-    pub(crate) fn render_with_width_raw(&self, width: usize) -> String {
+    /// Widths of the integer part and of the fraction part, separator included, of a decimal-aligned cell.
+    ///
+    /// Merged cells and cells narrower than their text don't take part in the alignment.
+    pub(crate) fn decimal_parts(&self) -> Option<(usize, usize)> {
+        let Align::Decimal(separator) = self.align else {
+            return None;
+        };
+        if self.merge.unwrap_or(0) > 0 {
+            return None;
+        }
+        let text = self.content.get_text()?;
+        let len = text.chars().count();
+        if self.width.is_some_and(|w| w < len) {
+            return None;
+        }
+        let integer = text.chars().position(|c| c == separator).unwrap_or(len);
+        Some((integer, len - integer))
+    }
+
+    /// Split the rendered cell into gutter, alignment fill and content.
+    ///
+    /// `fraction` is the widest fraction of the column, used by [Align::Decimal].
+    ///
+    /// Returns `[gutter_left, fill_left, fill_right, gutter_right]` and the spans of the content.
+    fn render_parts(&self, width: usize, fraction: usize) -> ([String; 4], Vec<Span>) {
         if self.content.have_default_padding() {
             let width = width - self.padding.left - self.padding.right;
-            let (content, w) = self.content.render_with_width(width, self.overflow);
-            let content = match self.align {
-                Align::Left => format!("{}{}", content, " ".repeat(width - w)),
-                Align::Right => format!("{}{}", " ".repeat(width - w), content),
+            let (content, w) = self.content.render_spans_with_width(width, self.overflow);
+            let (left, right) = match self.align {
+                Align::Left => (0, width - w),
+                Align::Right => (width - w, 0),
                 Align::Center => {
                     let left = (width - w) / 2;
-                    let right = width - w - left;
-                    format!("{}{}{}", " ".repeat(left), content, " ".repeat(right))
+                    (left, width - w - left)
+                }
+                Align::Decimal(_) => {
+                    let right = match self.decimal_parts() {
+                        Some((integer, own)) if integer + own == w => {
+                            fraction.saturating_sub(own).min(width - w)
+                        }
+                        _ => 0,
+                    };
+                    (width - w - right, right)
                 }
             };
-            format!(
-                " {}{}{} ",
-                " ".repeat(self.padding.left),
+            (
+                [
+                    format!(" {}", " ".repeat(self.padding.left)),
+                    " ".repeat(left),
+                    " ".repeat(right),
+                    format!("{} ", " ".repeat(self.padding.right)),
+                ],
                 content,
-                " ".repeat(self.padding.right)
             )
         } else {
-            let (content, _) = self.content.render_with_width(width + 2, self.overflow);
-            content
+            let (content, _) = self
+                .content
+                .render_spans_with_width(width + 2, self.overflow);
+            (Default::default(), content)
         }
     }
 
-    pub(crate) fn render_with_width(&self, width: usize) -> ColoredString {
-        let result = self.render_with_width_raw(width);
-        self.formatter
-            .iter()
-            .fold(ColoredString::from(result.as_str()), |acc, func| {
-                func.run(acc)
-            })
+    pub(crate) fn render_with_width_raw(&self, width: usize, fraction: usize) -> String {
+        let ([gutter_left, fill_left, fill_right, gutter_right], spans) =
+            self.render_parts(width, fraction);
+        let content = spans.iter().map(|s| s.text.as_str()).collect::<String>();
+        gutter_left + &fill_left + &content + &fill_right + &gutter_right
+    }
+
+    /// Render the cell with ANSI styles, applying the table-level `decoration` around the cell's own style.
+    pub(crate) fn render_with_width(
+        &self,
+        width: usize,
+        fraction: usize,
+        decoration: Decoration,
+        depth: ColorDepth,
+    ) -> String {
+        let ([gutter_left, fill_left, fill_right, gutter_right], spans) =
+            self.render_parts(width, fraction);
+        let style = decoration.around(self.style);
+        let paint = |s: &str, style: Style| {
+            let styled = self
+                .formatter
+                .iter()
+                .fold(style.apply(ColoredString::from(s)), |acc, func| {
+                    func.run(acc)
+                });
+            style.paint_colored(&styled, depth)
+        };
+        let link = |content: String| match self.link {
+            Some(ref link) => format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", link, content),
+            None => content,
+        };
+
+        // Spans are painted one by one, and links wrap the painted content so that formatters never see the URL.
+        // In both cases, the fill and gutters are painted separately.
+        let painted = match (&self.content, &self.link) {
+            (Content::Spans(_), _) => Some(
+                spans
+                    .iter()
+                    .map(|s| paint(&s.text, style.merge(s.style)))
+                    .collect::<String>(),
+            ),
+            (_, Some(_)) => Some(paint(
+                &spans.iter().map(|s| s.text.as_str()).collect::<String>(),
+                style,
+            )),
+            _ => None,
+        };
+        if let Some(content) = painted {
+            let content = link(content);
+            return match self.style_scope {
+                StyleScope::Content => {
+                    gutter_left + &fill_left + &content + &fill_right + &gutter_right
+                }
+                StyleScope::Aligned => {
+                    let (left, right) = (paint(&fill_left, style), paint(&fill_right, style));
+                    gutter_left + &left + &content + &right + &gutter_right
+                }
+                StyleScope::Cell => {
+                    let left = paint(&(gutter_left + &fill_left), style);
+                    left + &content + &paint(&(fill_right + &gutter_right), style)
+                }
+            };
+        }
+
+        let content: String = spans.into_iter().map(|s| s.text).collect();
+        match self.style_scope {
+            StyleScope::Content => format!(
+                "{}{}{}{}{}",
+                gutter_left,
+                fill_left,
+                paint(&content, style),
+                fill_right,
+                gutter_right
+            ),
+            StyleScope::Aligned => format!(
+                "{}{}{}",
+                gutter_left,
+                paint(&(fill_left + &content + &fill_right), style),
+                gutter_right
+            ),
+            StyleScope::Cell => paint(
+                &(gutter_left + &fill_left + &content + &fill_right + &gutter_right),
+                style,
+            ),
+        }
     }
 }
 
@@ -157,59 +316,184 @@ fn test_render_cell() {
     let cell = Cell::default()
         .with_content(Content::new("123"))
         .with_overflow(Overflow::Ellipsis);
-    let rendered = cell.render_with_width(2);
+    let rendered = cell.render_with_width(2, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!(" 1. ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis);
-    let rendered = cell.render_with_width(4);
+    let rendered = cell.render_with_width(4, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!(" 1... ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis);
-    let rendered = cell.render_with_width(6);
+    let rendered = cell.render_with_width(6, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!(" 123123 ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Hidden);
-    let rendered = cell.render_with_width(5);
+    let rendered = cell.render_with_width(5, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!(" 12312 ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis)
         .with_padding(Padding::new(1, 1));
-    let rendered = cell.render_with_width(6);
+    let rendered = cell.render_with_width(6, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!("  1...  ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis)
         .with_padding(Padding::new(1, 3));
-    let rendered = cell.render_with_width(6);
+    let rendered = cell.render_with_width(6, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!("  1.    ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis)
         .with_align(Align::Center);
-    let rendered = cell.render_with_width(10);
+    let rendered = cell.render_with_width(10, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!("   123123   ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis)
         .with_align(Align::Center);
-    let rendered = cell.render_with_width(9);
+    let rendered = cell.render_with_width(9, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!("  123123   ", format!("{}", rendered));
 
     let cell = Cell::default()
         .with_content(Content::new("123123"))
         .with_overflow(Overflow::Ellipsis)
         .with_align(Align::Right);
-    let rendered = cell.render_with_width(8);
+    let rendered = cell.render_with_width(8, 0, Decoration::NONE, ColorDepth::TrueColor);
     assert_eq!("   123123 ", format!("{}", rendered));
 }
+
+#[test]
+fn test_style_scope() {
+    use colored::Colorize;
+
+    let cell = Cell::default()
+        .with_content(Content::new("ab"))
+        .with_align(Align::Center)
+        .with_padding(Padding::new(1, 1))
+        .with_formatter(crate::fmt!(|s: ColoredString| s.replace(' ', "_").normal()));
+    let render = |cell: &Cell, width| {
+        cell.render_with_width(width, 0, Decoration::NONE, ColorDepth::TrueColor)
+    };
+    assert_eq!("___ab___", render(&cell, 6));
+    let cell = cell.with_style_scope(StyleScope::Aligned);
+    assert_eq!("  __ab__  ", render(&cell, 8));
+    let cell = cell.with_style_scope(StyleScope::Content);
+    assert_eq!("    ab    ", render(&cell, 8));
+}
+
+#[test]
+fn test_formatter_attributes() {
+    use colored::Colorize;
+
+    let cell = Cell::default()
+        .with_content(Content::new("x"))
+        .with_formatter(crate::fmt!(|s: ColoredString| s.reversed().blink().red()));
+    assert_eq!(
+        "\x1b[5;7;31m x \x1b[0m",
+        cell.render_with_width(1, 0, Decoration::NONE, ColorDepth::Ansi16)
+    );
+    let cell = Cell::default()
+        .with_content(Content::new("x"))
+        .with_formatter(crate::fmt!(|s: ColoredString| s.hidden()));
+    assert_eq!(
+        "\x1b[8m x \x1b[0m",
+        cell.render_with_width(1, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+}
+
+#[test]
+fn test_fixed_color() {
+    use crate::table::Color;
+    use colored::Colorize;
+
+    let cell = |color| {
+        Cell::default()
+            .with_content(Content::new("x"))
+            .with_style(Style::new().foreground(color))
+    };
+    for depth in [ColorDepth::TrueColor, ColorDepth::Ansi256] {
+        assert_eq!(
+            "\x1b[38;5;208m x \x1b[0m",
+            cell(Color::Fixed(208)).render_with_width(1, 0, Decoration::NONE, depth)
+        );
+    }
+    assert_eq!(
+        "\x1b[38;5;9m x \x1b[0m",
+        cell(Color::Fixed(9)).render_with_width(1, 0, Decoration::NONE, ColorDepth::Ansi256)
+    );
+    let recolored =
+        cell(Color::Fixed(208)).with_formatter(crate::fmt!(|s: ColoredString| s.blue()));
+    assert_eq!(
+        "\x1b[34m x \x1b[0m",
+        recolored.render_with_width(1, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+}
+
+#[test]
+fn test_link() {
+    let cell = Cell::default()
+        .with_content(Content::new("#42"))
+        .with_link(Some("https://example.com/pr/42".to_string()));
+    assert_eq!(cell.get_width(), Some(3));
+    assert_eq!(
+        " \x1b]8;;https://example.com/pr/42\x1b\\#42\x1b]8;;\x1b\\  ",
+        cell.render_with_width(4, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+    assert_eq!(" #42  ", cell.render_with_width_raw(4, 0));
+
+    let cell = Cell::default()
+        .with_content(Content::new("docs"))
+        .with_link(Some("https://ex.com/a".to_string()))
+        .with_formatter(crate::fmt!(|s: ColoredString| s.to_uppercase().into()));
+    assert_eq!(
+        " \x1b]8;;https://ex.com/a\x1b\\DOCS\x1b]8;;\x1b\\ ",
+        cell.render_with_width(4, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+}
+
+#[test]
+fn test_spans() {
+    let cell = Cell::default().with_content(Content::Spans(vec![
+        Span::new("ab", Style::new().bold()),
+        Span::plain("cd"),
+    ]));
+    assert_eq!(cell.get_width(), Some(4));
+    assert_eq!(" abcd  ", cell.render_with_width_raw(5, 0));
+    let cell = cell.with_style(Style::new().italic());
+    assert_eq!(
+        "\x1b[3m \x1b[0m\x1b[1;3mab\x1b[0m\x1b[3mcd\x1b[0m\x1b[3m  \x1b[0m",
+        cell.render_with_width(5, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+    let cell = cell.with_style_scope(StyleScope::Content);
+    assert_eq!(
+        " \x1b[1;3m...\x1b[0m ",
+        cell.render_with_width(3, 0, Decoration::NONE, ColorDepth::TrueColor)
+    );
+}
+
+#[test]
+fn test_decimal_align() {
+    let cell = |text: &str| {
+        Cell::default()
+            .with_content(Content::new(text))
+            .with_align(Align::Decimal('.'))
+    };
+    assert_eq!(cell("120.25").decimal_parts(), Some((3, 3)));
+    assert_eq!(cell("7").decimal_parts(), Some((1, 0)));
+    assert_eq!(cell("7").with_width(Some(0)).decimal_parts(), None);
+    assert_eq!(" 3.5  ", cell("3.5").render_with_width_raw(4, 3));
+    assert_eq!("   7    ", cell("7").render_with_width_raw(6, 3));
+    assert_eq!(" 120.25 ", cell("120.25").render_with_width_raw(6, 3));
+    assert_eq!(" 12... ", cell("12345.6").render_with_width_raw(5, 2));
+}
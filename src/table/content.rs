@@ -1,8 +1,32 @@
-use super::Overflow;
+use super::rule::parse_number;
+use super::{Overflow, Style, Value, ValueFormat};
+
+/// A piece of text with its own style, see [Content::Spans].
+///
+/// The span's style is layered on top of the cell's style.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Span {
+    pub fn new(text: impl ToString, style: Style) -> Self {
+        Self {
+            text: text.to_string(),
+            style,
+        }
+    }
+    pub fn plain(text: impl ToString) -> Self {
+        Self::new(text, Style::NONE)
+    }
+}
 
 /// Any possible content in a cell.
 /// 
 /// - Text(String): pure text.
+/// - Spans(Vec<Span>): text made of differently styled spans, measured and truncated like a single text.
+/// - Value(Value, ValueFormat): a typed value, formatted when the table renders. See [Content::value].
 /// - Splitter: horizontal splitter. Looks like "━".
 /// - None: empty content.
 /// 
@@ -11,10 +35,20 @@ use super::Overflow;
 /// // You can use a string to create one.
 /// assert_eq!(<&str as Into<Content>>::into("123").get_content(), Content::Text("123".to_string()).get_content());
 /// assert_eq!(Content::new("123").get_content(), Content::Text("123".to_string()).get_content());
+///
+/// // A bold name followed by a dim annotation.
+/// # use table_formatter::table::{Span, Style};
+/// let content = Content::Spans(vec![
+///     Span::new("serde", Style::new().bold()),
+///     Span::new(" (optional)", Style::new().dim()),
+/// ]);
+/// assert_eq!(content.get_width(), Some(16));
 /// ```
 #[derive(Debug, Clone, Default)]
 pub enum Content {
     Text(String),
+    Spans(Vec<Span>),
+    Value(Value, ValueFormat),
     Splitter,
     #[default]
     None,
@@ -45,7 +79,7 @@ fn render(width: usize, last: usize, s: &str) -> (usize, String, bool) {
         len += 1;
     }
 
-    if matches!(chars.next(), Some(_)) {
+    if chars.next().is_some() {
         overflow = true;
     }
 
@@ -61,25 +95,118 @@ impl Content {
         Self::Text(s.to_string())
     }
 
+    /// Create a typed value with the default [ValueFormat].
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Content, Unit, Value, ValueFormat};
+    /// assert_eq!(Content::value(1.5).get_text().unwrap(), "1.5");
+    /// assert_eq!(Content::value(None::<i64>).get_text().unwrap(), "");
+    /// let content = Content::Value(Value::Float(0.25), ValueFormat::default().with_unit(Unit::Percent));
+    /// assert_eq!(content.get_text().unwrap(), "25%");
+    /// ```
+    ///
+    /// [ValueFormat]: struct.ValueFormat.html
+    pub fn value(value: impl Into<Value>) -> Self {
+        Self::Value(value.into(), ValueFormat::default())
+    }
+
     pub fn get_width(&self) -> Option<usize> {
-        if let Self::Text(ref v) = self {
-            Some(v.chars().count())
-        } else {
-            None
+        match self {
+            Self::Text(ref v) => Some(v.chars().count()),
+            Self::Spans(ref spans) => Some(spans.iter().map(|s| s.text.chars().count()).sum()),
+            Self::Value(ref v, ref f) => Some(f.format(v).chars().count()),
+            _ => None,
         }
     }
 
     pub fn have_default_padding(&self) -> bool {
-        matches!(self, Content::Text(_))
+        matches!(
+            self,
+            Content::Text(_) | Content::Spans(_) | Content::Value(..)
+        )
+    }
+
+    /// The text shown by the content, if it has some.
+    pub fn get_text(&self) -> Option<String> {
+        match self {
+            Self::Text(ref t) => Some(t.clone()),
+            Self::Spans(ref spans) => Some(spans.iter().map(|s| s.text.as_str()).collect()),
+            Self::Value(ref v, ref f) => Some(f.format(v)),
+            _ => None,
+        }
+    }
+
+    /// The content as a number: the value of numeric [Content::Value]s, or the parsed text.
+    ///
+    /// [Content::Value]: #variant.Value
+    pub fn get_number(&self) -> Option<f64> {
+        match self {
+            Self::Value(ref v, _) => v.as_f64(),
+            _ => parse_number(&self.get_text()?),
+        }
+    }
+
+    /// Check if the content is a numeric [Content::Value].
+    ///
+    /// [Content::Value]: #variant.Value
+    pub fn is_number(&self) -> bool {
+        matches!(self, Self::Value(v, _) if v.is_number())
+    }
+
+    /// Render the content like [render_with_width], keeping the style of each span.
+    ///
+    /// Contents other than [Content::Spans] give a single unstyled span.
+    /// An ellipsis takes the style of the first character it replaces.
+    ///
+    /// [render_with_width]: #method.render_with_width
+    pub(crate) fn render_spans_with_width(
+        &self,
+        width: usize,
+        overflow: Overflow,
+    ) -> (Vec<Span>, usize) {
+        let Self::Spans(ref spans) = self else {
+            let (s, w) = self.render_with_width(width, overflow);
+            return (vec![Span::plain(s)], w);
+        };
+        let (text, w) = self.render_with_width(width, overflow);
+        let truncated = matches!(overflow, Overflow::Ellipsis) && self.get_width() > Some(width);
+        let dots = match truncated {
+            true if width < 3 => 1,
+            true => 3,
+            false => 0,
+        };
+        let prefix = text.chars().count() - dots;
+        let mut styles = spans
+            .iter()
+            .flat_map(|s| s.text.chars().map(move |_| s.style));
+        let mut result: Vec<Span> = vec![];
+        let mut ellipsis = Style::NONE;
+        for (i, ch) in text.chars().enumerate() {
+            let style = if i <= prefix {
+                styles.next().unwrap_or_default()
+            } else {
+                ellipsis
+            };
+            ellipsis = style;
+            match result.last_mut() {
+                Some(last) if last.style == style => last.text.push(ch),
+                _ => result.push(Span::new(ch, style)),
+            }
+        }
+        (result, w)
     }
 
     pub(crate) fn render_with_width(&self, width: usize, overflow: Overflow) -> (String, usize) {
         match self {
+            Self::Spans(_) | Self::Value(..) => {
+                Self::Text(self.get_text().unwrap_or_default()).render_with_width(width, overflow)
+            }
             Self::Text(ref v) => match overflow {
                 Overflow::Ellipsis => {
-                    let (w, s, flag) = render(width, if width < 3 { 1 } else { 3 }, v);
+                    let last = if width < 3 { width.min(1) } else { 3 };
+                    let (w, s, flag) = render(width, last, v);
                     if flag {
-                        (s + if width < 3 { "." } else { "..." }, width)
+                        (s + &".".repeat(last), width)
                     } else {
                         (s, w)
                     }
@@ -140,3 +267,28 @@ fn test_render_cell() {
     assert_eq!("         ", t.as_str());
     assert_eq!(9, w);
 }
+
+#[test]
+fn test_render_spans() {
+    let bold = Style::new().bold();
+    let dim = Style::new().dim();
+    let content = Content::Spans(vec![Span::new("abc", bold), Span::new("defg", dim)]);
+    assert_eq!(content.get_width(), Some(7));
+    assert_eq!(
+        content.render_spans_with_width(10, Overflow::Ellipsis),
+        (vec![Span::new("abc", bold), Span::new("defg", dim)], 7)
+    );
+    assert_eq!(
+        content.render_spans_with_width(5, Overflow::Hidden),
+        (vec![Span::new("abc", bold), Span::new("de", dim)], 5)
+    );
+    assert_eq!(
+        content.render_spans_with_width(5, Overflow::Ellipsis),
+        (vec![Span::new("ab...", bold)], 5)
+    );
+    assert_eq!(
+        content.render_spans_with_width(6, Overflow::Ellipsis),
+        (vec![Span::new("abc", bold), Span::new("...", dim)], 6)
+    );
+    assert_eq!(content.render_with_width(6, Overflow::Ellipsis).0, "abc...");
+}
@@ -0,0 +1,183 @@
+use std::io;
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Table};
+
+impl Table {
+    /// Read comma-separated values. See [from_delimited].
+    ///
+    /// [from_delimited]: #method.from_delimited
+    pub fn from_csv(reader: impl io::Read, header: bool) -> Result<Table, TableError> {
+        Table::from_delimited(reader, ',', header)
+    }
+
+    /// Read tab-separated values. See [from_delimited].
+    ///
+    /// [from_delimited]: #method.from_delimited
+    pub fn from_tsv(reader: impl io::Read, header: bool) -> Result<Table, TableError> {
+        Table::from_delimited(reader, '\t', header)
+    }
+
+    /// Read delimited text, one record per line.
+    ///
+    /// Fields may be quoted with `"`, in which case they can contain the delimiter, line breaks and `""` for a quote.
+    /// Cells are rendered on one line, so the line breaks of a field become spaces. Empty lines are skipped. When `header` is set, the first record becomes the header, like in [create].
+    ///
+    /// Every record must have as many fields as the first one.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// let csv = "name,note\nlint,\"ok, fast\"\n";
+    /// let table = Table::from_csv(csv.as_bytes(), true).unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///     " name  note     \n━━━━━━━━━━━━━━━━\n lint  ok, fast \n"
+    /// );
+    /// ```
+    ///
+    /// [create]: #method.create
+    pub fn from_delimited(
+        mut reader: impl io::Read,
+        delimiter: char,
+        header: bool,
+    ) -> Result<Table, TableError> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(TableError::ReadError)?;
+        let records = parse(&text, delimiter)?;
+
+        let mut rows = records.into_iter().map(|(_, fields)| {
+            fields
+                .into_iter()
+                .map(|f| Cell::default().with_content(Content::Text(one_line(f))))
+                .collect::<Vec<_>>()
+        });
+        match (header, rows.next()) {
+            (true, Some(h)) => Ok(Table::create(h, rows.collect(), true)),
+            (_, first) => Ok(Table::new(first.into_iter().chain(rows).collect())),
+        }
+    }
+}
+
+/// Replace the line breaks of `field` with spaces.
+fn one_line(field: String) -> String {
+    if !field.contains(['\n', '\r']) {
+        return field;
+    }
+    field.replace("\r\n", " ").replace(['\n', '\r'], " ")
+}
+
+/// Split `text` into records, each with the line it starts on.
+fn parse(text: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, TableError> {
+    let mut records: Vec<(usize, Vec<String>)> = vec![];
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = vec![];
+        let mut field = String::new();
+        // Whether the current field was quoted, so that `""` is not an empty record.
+        let mut quoted = false;
+        loop {
+            match chars.next() {
+                None => break,
+                Some('\n') => {
+                    line += 1;
+                    break;
+                }
+                Some('\r') if chars.peek() == Some(&'\n') => {}
+                Some('"') if field.is_empty() && !quoted => {
+                    quoted = true;
+                    let open = line;
+                    loop {
+                        match chars.next() {
+                            None => return Err(TableError::UnterminatedQuote { line: open }),
+                            Some('"') if chars.peek() == Some(&'"') => {
+                                chars.next();
+                                field.push('"');
+                            }
+                            Some('"') => break,
+                            Some(c) => {
+                                if c == '\n' {
+                                    line += 1;
+                                }
+                                field.push(c);
+                            }
+                        }
+                    }
+                    match chars.peek() {
+                        Some(&c) if c != delimiter && c != '\n' && c != '\r' => {
+                            return Err(TableError::InvalidQuote { line })
+                        }
+                        _ => {}
+                    }
+                }
+                Some('"') => return Err(TableError::InvalidQuote { line }),
+                Some(c) if c == delimiter => {
+                    fields.push(std::mem::take(&mut field));
+                    quoted = false;
+                }
+                Some(c) => field.push(c),
+            }
+        }
+        if fields.is_empty() && field.is_empty() && !quoted {
+            continue;
+        }
+        fields.push(field);
+
+        if let Some((_, first)) = records.first() {
+            if first.len() != fields.len() {
+                return Err(TableError::InvalidRecordLength {
+                    line: start,
+                    expected: first.len(),
+                    actual: fields.len(),
+                });
+            }
+        }
+        records.push((start, fields));
+    }
+    Ok(records)
+}
+
+#[test]
+fn test_parse_delimited() {
+    let fields = |records: Vec<(usize, Vec<String>)>| {
+        records
+            .into_iter()
+            .map(|(line, f)| (line, f.join("|")))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        fields(parse("a,b\r\n\n\"x, \"\"y\"\"\",\"multi\nline\"\n,\n", ',').unwrap()),
+        vec![
+            (1, "a|b".to_string()),
+            (3, "x, \"y\"|multi\nline".to_string()),
+            (5, "|".to_string()),
+        ]
+    );
+    assert_eq!(
+        fields(parse("a\tb,c\n\"\"\t", '\t').unwrap()),
+        vec![(1, "a|b,c".to_string()), (2, "|".to_string())]
+    );
+    assert!(matches!(
+        parse("a,b\n\"c\nd,e\n", ','),
+        Err(TableError::UnterminatedQuote { line: 2 })
+    ));
+    assert!(matches!(
+        parse("a,b\nc,\"d\"e\n", ','),
+        Err(TableError::InvalidQuote { line: 2 })
+    ));
+    assert!(matches!(
+        parse("a,b\nc,d\"\n", ','),
+        Err(TableError::InvalidQuote { line: 2 })
+    ));
+    assert!(matches!(
+        parse("a,b\n\"x\ny\",z\nc\n", ','),
+        Err(TableError::InvalidRecordLength {
+            line: 4,
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
@@ -0,0 +1,259 @@
+//! Reading and editing the rows, columns and cells of a built [Table].
+//!
+//! Rows are counted from the first row after the header, so the header made by [Table::create] can't be moved or removed.
+//! Merge errors report positions in the whole table, header rows included, like rendering does.
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Table};
+
+use super::table::header_rows;
+
+impl Table {
+    /// The number of rows, header excluded.
+    pub fn row_count(&self) -> usize {
+        self.table.len() - self.header.min(self.table.len())
+    }
+
+    /// The number of columns, as given by the first row.
+    pub fn column_count(&self) -> usize {
+        self.table.first().map_or(0, |r| r.len())
+    }
+
+    /// Iterate over the rows, header excluded.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.table[self.header.min(self.table.len())..]
+            .iter()
+            .map(|r| r.as_slice())
+    }
+
+    /// The cell in column `x` of row `y`.
+    pub fn get_cell(&self, x: usize, y: usize) -> Result<&Cell, TableError> {
+        let (x, y) = self.cell_index(x, y)?;
+        Ok(&self.table[y][x])
+    }
+
+    /// Replace the cell in column `x` of row `y`, returning the previous one.
+    ///
+    /// The cell can't be covered by a merged cell on its left, and if it merges, the cells it covers must be empty.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// # use table_formatter::cell;
+    /// let mut table = Table::create(vec![cell!("a"), cell!("b")], vec![vec![cell!("1"), cell!("2")]], true);
+    /// table.set_cell(1, 0, cell!("3")).unwrap();
+    /// assert!(table.set_cell(2, 0, cell!("4")).is_err());
+    /// assert_eq!(table.to_string_with(Renderer::Raw).unwrap(), " a  b \n━━━━━━\n 1  3 \n");
+    /// ```
+    pub fn set_cell(&mut self, x: usize, y: usize, cell: Cell) -> Result<Cell, TableError> {
+        let (x, y) = self.cell_index(x, y)?;
+        if merged_at(&self.table[y], x).is_some_and(|start| start < x) {
+            return Err(TableError::MergeSplit { x, y });
+        }
+        let mut row = self.table[y].clone();
+        let old = std::mem::replace(&mut row[x], cell);
+        check_merges(&row, y)?;
+        self.table[y] = row;
+        Ok(old)
+    }
+
+    /// Add a row at the end of the table.
+    pub fn push_row(&mut self, row: Vec<Cell>) -> Result<(), TableError> {
+        self.insert_row(self.row_count(), row)
+    }
+
+    /// Insert a row before row `y`, or at the end if `y` is the number of rows.
+    ///
+    /// The row must have as many cells as the table has columns, and its merges must be valid.
+    pub fn insert_row(&mut self, y: usize, row: Vec<Cell>) -> Result<(), TableError> {
+        let y = self.row_index(y, true)?;
+        if !self.table.is_empty() && row.len() != self.column_count() {
+            return Err(TableError::InvalidTableSize {
+                row: y,
+                expected: self.column_count(),
+                actual: row.len(),
+            });
+        }
+        check_merges(&row, y)?;
+        self.table.insert(y, row);
+        Ok(())
+    }
+
+    /// Remove row `y` and return its cells.
+    pub fn remove_row(&mut self, y: usize) -> Result<Vec<Cell>, TableError> {
+        let y = self.row_index(y, false)?;
+        Ok(self.table.remove(y))
+    }
+
+    /// Add a column at the right of the table. See [insert_column].
+    ///
+    /// [insert_column]: #method.insert_column
+    pub fn push_column(
+        &mut self,
+        header: Option<Cell>,
+        cells: Vec<Cell>,
+    ) -> Result<(), TableError> {
+        self.insert_column(self.column_count(), header, cells)
+    }
+
+    /// Insert a column before column `x`, or at the right if `x` is the number of columns.
+    ///
+    /// `cells` gives one cell per row. In a table made by [create], `header` goes in the header,
+    /// formatted the same way, and defaults to an empty cell. Tables without header take `None`,
+    /// and giving them a header cell is an error.
+    ///
+    /// The column can't be inserted through a merged cell.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// # use table_formatter::cell;
+    /// let mut table = Table::create(vec![cell!("a")], vec![vec![cell!("1")], vec![cell!("2")]], true);
+    /// table.insert_column(0, Some(cell!("id")), vec![cell!("x"), cell!("y")]).unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///     " id  a \n━━━━━━━\n x   1 \n y   2 \n"
+    /// );
+    /// ```
+    ///
+    /// [create]: #method.create
+    pub fn insert_column(
+        &mut self,
+        x: usize,
+        header: Option<Cell>,
+        cells: Vec<Cell>,
+    ) -> Result<(), TableError> {
+        let x = self.column_index(x, true)?;
+        if self.header == 0 && header.is_some() {
+            return Err(TableError::NoHeader { column: x });
+        }
+        if cells.len() != self.row_count() {
+            return Err(TableError::InvalidColumnSize {
+                column: x,
+                expected: self.row_count(),
+                actual: cells.len(),
+            });
+        }
+        for (y, row) in self.table.iter().enumerate() {
+            if merged_at(row, x).is_some_and(|start| start < x) {
+                return Err(TableError::MergeSplit { x, y });
+            }
+        }
+        let column = match self.header {
+            0 => vec![],
+            n => header_rows(vec![header.unwrap_or_default()], n > 1),
+        };
+        let column = column.into_iter().flatten().chain(cells);
+        for (row, cell) in self.table.iter_mut().zip(column) {
+            row.insert(x, cell);
+        }
+        Ok(())
+    }
+
+    /// Remove column `x` and return its cells, header excluded.
+    ///
+    /// The column can't be part of a merged cell.
+    pub fn remove_column(&mut self, x: usize) -> Result<Vec<Cell>, TableError> {
+        let x = self.column_index(x, false)?;
+        for (y, row) in self.table.iter().enumerate() {
+            if let Some(start) = merged_at(row, x) {
+                if start < x || row[x].get_merge().unwrap_or(0) > 0 {
+                    return Err(TableError::MergeSplit { x, y });
+                }
+            }
+        }
+        let header = self.header.min(self.table.len());
+        Ok(self
+            .table
+            .iter_mut()
+            .map(|row| row.remove(x))
+            .skip(header)
+            .collect())
+    }
+
+    /// The index in `self.table` of row `y`, which may be one past the end when `end` is set.
+    fn row_index(&self, y: usize, end: bool) -> Result<usize, TableError> {
+        let rows = self.row_count();
+        if y < rows || (end && y == rows) {
+            Ok(self.header.min(self.table.len()) + y)
+        } else {
+            Err(TableError::RowOutOfTable { row: y, rows })
+        }
+    }
+
+    /// The position in `self.table` of the cell in column `x` of row `y`, checked against that row,
+    /// which may be shorter than the first one in an invalid table.
+    fn cell_index(&self, x: usize, y: usize) -> Result<(usize, usize), TableError> {
+        let y = self.row_index(y, false)?;
+        let columns = self.table[y].len();
+        if x < columns {
+            Ok((x, y))
+        } else {
+            Err(TableError::ColumnOutOfTable { column: x, columns })
+        }
+    }
+
+    fn column_index(&self, x: usize, end: bool) -> Result<usize, TableError> {
+        let columns = self.column_count();
+        if x < columns || (end && x == columns) {
+            Ok(x)
+        } else {
+            Err(TableError::ColumnOutOfTable { column: x, columns })
+        }
+    }
+}
+
+/// The column of the merged cell covering column `x` of `row`, if any, which may be `x` itself.
+pub(super) fn merged_at(row: &[Cell], x: usize) -> Option<usize> {
+    let mut start = 0;
+    while let Some(cell) = row.get(start) {
+        let end = start + cell.get_merge().unwrap_or(0);
+        if x <= end {
+            return (end > start).then_some(start);
+        }
+        start = end + 1;
+    }
+    None
+}
+
+/// Check that the merged cells of row `y` stay in the row and only cover empty cells.
+fn check_merges(row: &[Cell], y: usize) -> Result<(), TableError> {
+    let mut x = 0;
+    while let Some(cell) = row.get(x) {
+        let merge = cell.get_merge().unwrap_or(0);
+        let Some(covered) = row.get(x + 1..=x + merge) else {
+            return Err(TableError::MergeOutOfTable {
+                x,
+                y,
+                merge,
+                max: row.len() - x - 1,
+            });
+        };
+        if let Some(mx) = covered
+            .iter()
+            .position(|c| !matches!(c.get_content(), Content::None))
+        {
+            return Err(TableError::MergeOverwrite { x: x + mx + 1, y });
+        }
+        x += merge + 1;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merged_at() {
+    let mut row = vec![Cell::default().with_content(Content::new("a"))];
+    row.extend(Cell::default().with_content(Content::new("b")).with_span(2));
+    assert_eq!(merged_at(&row, 0), None);
+    assert_eq!(merged_at(&row, 1), Some(1));
+    assert_eq!(merged_at(&row, 3), Some(1));
+    assert_eq!(merged_at(&row, 4), None);
+    assert!(check_merges(&row, 0).is_ok());
+    assert!(matches!(
+        check_merges(&row[..3], 2),
+        Err(TableError::MergeOutOfTable {
+            x: 1,
+            y: 2,
+            merge: 2,
+            max: 1
+        })
+    ));
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Style, Table, Value, ValueFormat};
+
+use super::table::splitter_row;
+
+/// A custom reduction of the numbers of a column, used by [Aggregate::Custom].
+///
+/// [Aggregate::Custom]: enum.Aggregate.html#variant.Custom
+pub type AggregateFn = dyn Fn(&[f64]) -> Option<f64> + Send + Sync;
+
+/// A value computed from the numeric cells of a column. Cells which are not numbers are ignored.
+#[derive(Clone)]
+pub enum Aggregate {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// The number of numeric cells.
+    Count,
+    Custom(Arc<AggregateFn>),
+}
+
+impl Aggregate {
+    /// Reduce `values`, or `None` if there is nothing to show.
+    fn compute(&self, values: &[f64]) -> Option<f64> {
+        match self {
+            Aggregate::Sum => Some(values.iter().sum()),
+            Aggregate::Mean => {
+                (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+            }
+            Aggregate::Min => values.iter().copied().reduce(f64::min),
+            Aggregate::Max => values.iter().copied().reduce(f64::max),
+            Aggregate::Count => Some(values.len() as f64),
+            Aggregate::Custom(f) => f(values),
+        }
+    }
+}
+
+/// A footer row, shown under the data rows and separated from them like the header.
+///
+/// Cells are either set by hand or computed from their column when the table renders,
+/// so a filtered table or a page shows the aggregates of its own rows.
+/// Computed values are right-aligned, or take the format and alignment of the first numeric [Value] of the column.
+///
+/// Footer rows are left out of stripes, rules and color scales, and get their own style, bold by default.
+///
+/// ```rust
+/// # use table_formatter::table::{Aggregate, Cell, Content, Footer, Renderer, Table};
+/// # use table_formatter::cell;
+/// let count = |n: i64| Cell::default().with_content(Content::value(n));
+/// let table = Table::create(
+///     vec![cell!("crate"), cell!("downloads")],
+///     vec![vec![cell!("serde"), count(1200)], vec![cell!("regex"), count(800)]],
+///     true,
+/// )
+/// .with_footer(Footer::new().with_label(0, "total").with_aggregate(1, Aggregate::Sum));
+/// assert_eq!(
+///     table.to_string_with(Renderer::Raw).unwrap(),
+///     " crate  downloads \n━━━━━━━━━━━━━━━━━━\n serde       1200 \n regex        800 \n━━━━━━━━━━━━━━━━━━\n total       2000 \n"
+/// );
+/// ```
+///
+/// [Value]: enum.Value.html
+#[derive(Clone)]
+pub struct Footer {
+    cells: Vec<(usize, FooterCell)>,
+    splitter: bool,
+    style: Style,
+}
+
+#[derive(Clone)]
+enum FooterCell {
+    Cell(Box<Cell>),
+    Aggregate(Aggregate),
+}
+
+impl Default for Footer {
+    fn default() -> Self {
+        Self {
+            cells: vec![],
+            splitter: true,
+            style: Style::new().bold(),
+        }
+    }
+}
+
+impl Footer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Put `cell` in `column`.
+    pub fn with_cell(mut self, column: usize, cell: Cell) -> Self {
+        self.cells.push((column, FooterCell::Cell(Box::new(cell))));
+        self
+    }
+    /// Put a text cell in `column`.
+    pub fn with_label(self, column: usize, label: impl ToString) -> Self {
+        self.with_cell(column, Cell::default().with_content(Content::new(label)))
+    }
+    /// Compute `column` with `aggregate`.
+    pub fn with_aggregate(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.cells.push((column, FooterCell::Aggregate(aggregate)));
+        self
+    }
+    /// Whether a splitter row separates the footer from the rows above, `true` by default.
+    pub fn with_splitter(mut self, splitter: bool) -> Self {
+        self.splitter = splitter;
+        self
+    }
+    /// The style of the footer, layered under the cells' own styles.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Build the rows of the footer for `table`, splitter included.
+    fn rows(&self, table: &Table, columns: usize) -> Result<Vec<Vec<Cell>>, TableError> {
+        let mut row = vec![Cell::default(); columns];
+        for (column, cell) in self.cells.iter() {
+            let Some(slot) = row.get_mut(*column) else {
+                return Err(TableError::ColumnOutOfTable {
+                    column: *column,
+                    columns,
+                });
+            };
+            let cell = match cell {
+                FooterCell::Cell(cell) => (**cell).clone(),
+                FooterCell::Aggregate(aggregate) => aggregate_cell(table, *column, aggregate),
+            };
+            let style = self.style.merge(cell.get_style());
+            *slot = cell.with_style(style);
+        }
+        let mut rows = vec![];
+        if self.splitter {
+            rows.push(splitter_row(columns));
+        }
+        rows.push(row);
+        Ok(rows)
+    }
+}
+
+/// The cell showing `aggregate` over the data rows of `column`.
+fn aggregate_cell(table: &Table, column: usize, aggregate: &Aggregate) -> Cell {
+    let cells = table
+        .rows()
+        .filter_map(|r| r.get(column))
+        .collect::<Vec<_>>();
+    let values = cells
+        .iter()
+        .filter_map(|c| c.get_content().get_number())
+        .collect::<Vec<_>>();
+    let model = cells
+        .iter()
+        .find(|c| c.get_content().get_number().is_some());
+    let format = match (aggregate, model.map(|c| c.get_content())) {
+        (Aggregate::Count, _) => ValueFormat::default(),
+        (_, Some(Content::Value(_, format))) => format.clone(),
+        _ => ValueFormat::default(),
+    };
+    let integers = values.iter().all(|v| v.fract() == 0.0);
+    let value = match aggregate.compute(&values) {
+        None => Value::Null,
+        Some(v) if v.fract() == 0.0 && v.abs() < 1e15 && integers => Value::Integer(v as i64),
+        Some(v) => Value::Float(v),
+    };
+    let cell = Cell::default().with_content(Content::Value(value, format));
+    match model {
+        Some(model) if model.get_content().is_number() => cell.with_align(model.get_align()),
+        _ => cell,
+    }
+}
+
+impl Table {
+    /// Add a footer row under the data rows. See [Footer].
+    ///
+    /// [Footer]: struct.Footer.html
+    pub fn with_footer(mut self, footer: Footer) -> Table {
+        self.footers.push(footer);
+        self
+    }
+    pub fn add_footer(&mut self, footer: Footer) {
+        self.footers.push(footer);
+    }
+    pub fn clear_footers(&mut self) {
+        self.footers.clear();
+    }
+
+    /// The rows of every footer, computed for the current data rows.
+    pub(super) fn footer_rows(&self, columns: usize) -> Result<Vec<Vec<Cell>>, TableError> {
+        let mut rows = vec![];
+        for footer in self.footers.iter() {
+            rows.extend(footer.rows(self, columns)?);
+        }
+        Ok(rows)
+    }
+}
+
+#[test]
+fn test_aggregate() {
+    let values = [3.0, 1.0, 2.0];
+    assert_eq!(Aggregate::Sum.compute(&values), Some(6.0));
+    assert_eq!(Aggregate::Mean.compute(&values), Some(2.0));
+    assert_eq!(Aggregate::Min.compute(&values), Some(1.0));
+    assert_eq!(Aggregate::Max.compute(&values), Some(3.0));
+    assert_eq!(Aggregate::Count.compute(&values), Some(3.0));
+    assert_eq!(Aggregate::Mean.compute(&[]), None);
+    assert_eq!(Aggregate::Sum.compute(&[]), Some(0.0));
+    let range = Aggregate::Custom(Arc::new(|v: &[f64]| {
+        let max = v.iter().copied().reduce(f64::max)?;
+        Some(max - v.iter().copied().reduce(f64::min)?)
+    }));
+    assert_eq!(range.compute(&values), Some(2.0));
+}
@@ -0,0 +1,135 @@
+use serde_json::Value as Json;
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Table, Value};
+
+impl Table {
+    /// Build a table from a JSON array. Requires the `json` feature.
+    ///
+    /// - An array of objects gives one column per key, in the order the keys are first seen, under a header.
+    ///   Objects missing a key get an empty cell.
+    /// - An array of arrays gives one row per array, without header. The arrays must have the same length.
+    ///
+    /// Numbers, booleans and `null` become typed [Value]s. Nested arrays and objects are shown as compact JSON.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// let json = serde_json::json!([
+    ///     {"name": "api", "replicas": 3},
+    ///     {"name": "worker", "labels": {"tier": "batch"}},
+    /// ]);
+    /// let table = Table::from_json(&json).unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Markdown).unwrap(),
+    ///     "┃name┃replicas┃labels┃\n┃:--┃:--┃:--┃\n┃━━━┃━━━┃━━━┃\n┃api┃3┃┃\n┃worker┃┃{\"tier\":\"batch\"}┃\n"
+    /// );
+    /// ```
+    ///
+    /// [Value]: enum.Value.html
+    pub fn from_json(json: &Json) -> Result<Table, TableError> {
+        let Json::Array(items) = json else {
+            return Err(TableError::JsonError("expected an array".to_string()));
+        };
+        match items.first() {
+            None => Ok(Table::new(vec![])),
+            Some(Json::Object(_)) => {
+                let mut keys: Vec<&String> = vec![];
+                for (index, item) in items.iter().enumerate() {
+                    let Json::Object(map) = item else {
+                        return Err(TableError::JsonError(format!(
+                            "item {} is not an object",
+                            index
+                        )));
+                    };
+                    for key in map.keys() {
+                        if !keys.contains(&key) {
+                            keys.push(key);
+                        }
+                    }
+                }
+                let header = keys
+                    .iter()
+                    .map(|k| Cell::default().with_content(Content::new(k)))
+                    .collect();
+                let rows = items
+                    .iter()
+                    .map(|item| {
+                        keys.iter()
+                            .map(|k| {
+                                Cell::default()
+                                    .with_content(item.get(k).map_or(Content::None, content))
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Ok(Table::create(header, rows, true))
+            }
+            Some(Json::Array(_)) => {
+                let rows = items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| match item {
+                        Json::Array(row) => Ok(row
+                            .iter()
+                            .map(|v| Cell::default().with_content(content(v)))
+                            .collect()),
+                        _ => Err(TableError::JsonError(format!(
+                            "item {} is not an array",
+                            index
+                        ))),
+                    })
+                    .collect::<Result<Vec<Vec<Cell>>, _>>()?;
+                let table = Table::new(rows);
+                table.validate()?;
+                Ok(table)
+            }
+            Some(_) => Err(TableError::JsonError(
+                "expected an array of objects or arrays".to_string(),
+            )),
+        }
+    }
+}
+
+/// The content of a cell holding `json`.
+pub(crate) fn content(json: &Json) -> Content {
+    match json {
+        Json::Null => Content::value(Value::Null),
+        Json::Bool(b) => Content::value(*b),
+        Json::Number(n) => match (n.as_i64(), n.as_f64()) {
+            (Some(i), _) => Content::value(i),
+            (None, Some(f)) => Content::value(f),
+            (None, None) => Content::new(n),
+        },
+        Json::String(s) => Content::new(s),
+        nested => Content::new(nested),
+    }
+}
+
+#[test]
+fn test_from_json() {
+    let table = Table::from_json(&serde_json::json!([[1, "a", null], [2.5, [true], {}]])).unwrap();
+    assert_eq!(
+        table.to_string_with(crate::table::Renderer::Raw).unwrap(),
+        "   1  a          \n 2.5  [true]  {} \n"
+    );
+    assert!(matches!(
+        Table::from_json(&serde_json::json!([[1], [2, 3]])),
+        Err(TableError::InvalidTableSize {
+            row: 1,
+            expected: 1,
+            actual: 2
+        })
+    ));
+    assert!(matches!(
+        Table::from_json(&serde_json::json!([{"a": 1}, [2]])),
+        Err(TableError::JsonError(_))
+    ));
+    assert!(matches!(
+        Table::from_json(&serde_json::json!({"a": 1})),
+        Err(TableError::JsonError(_))
+    ));
+    assert!(matches!(
+        Table::from_json(&serde_json::json!([1, 2])),
+        Err(TableError::JsonError(_))
+    ));
+}
@@ -0,0 +1,291 @@
+//! Layout engine shared by every text backend.
+//!
+//! The layout pass measures the columns, resolves merged cells and produces a list of [Line]s.
+//! Backends only have to decide how each positioned cell and border is written.
+
+use std::fmt;
+
+use crate::error::TableError;
+use crate::table::{Border, Cell, ColorDepth, Content, Style};
+
+use super::style::Decoration;
+
+/// Style of the table borders.
+const BORDER: Style = Style::new().bold();
+
+/// A cell placed on a row.
+pub(crate) struct Placed<'a> {
+    pub(crate) cell: &'a Cell,
+    /// Index of the first column covered by the cell.
+    pub(crate) x: usize,
+    /// Table-level styles applied around the cell's own style.
+    pub(crate) decoration: Decoration,
+    /// Inner width of the cell, without the one-space gutters on both sides.
+    pub(crate) width: usize,
+    /// Widest fraction of the decimal-aligned cells of the column, separator included.
+    pub(crate) fraction: usize,
+}
+
+/// A single line of output.
+pub(crate) enum Line<'a> {
+    /// A horizontal border spanning the whole table.
+    Rule { width: usize },
+    /// A row of cells, with the junctions to the vertical borders.
+    Row {
+        left: bool,
+        right: bool,
+        cells: Vec<Placed<'a>>,
+    },
+    /// A line of text under the table.
+    Caption(&'a str),
+}
+
+/// The intermediate representation of a table, ready to be written.
+pub(crate) struct Layout<'a> {
+    pub(crate) lines: Vec<Line<'a>>,
+}
+
+impl<'a> Layout<'a> {
+    /// Lay out `rows`, which must already be validated to have `columns` cells each.
+    ///
+    /// Columns are at least as wide as given by `min_widths`. `decorate` gives the table-level styles of the cell at `(x, y)`.
+    pub(crate) fn build(
+        rows: &[&'a [Cell]],
+        columns: usize,
+        min_widths: &[usize],
+        border: Border,
+        decorate: &dyn Fn(usize, usize, &Cell) -> Decoration,
+    ) -> Result<Self, TableError> {
+        let (mut widths, fractions) = measure(rows.iter().copied(), columns)?;
+        for (width, min) in widths.iter_mut().zip(min_widths) {
+            *width = (*width).max(*min);
+        }
+        let mut lines = vec![];
+        if border.top {
+            lines.push(Line::Rule {
+                width: table_width(&widths, border),
+            });
+        }
+        for (y, &row) in rows.iter().enumerate() {
+            let mut cells = place_row(row, y, &widths, &fractions)?;
+            for placed in cells.iter_mut() {
+                placed.decoration = decorate(placed.x, y, placed.cell);
+            }
+            lines.push(Line::Row {
+                left: border.left,
+                right: border.right,
+                cells,
+            });
+        }
+        if border.bottom {
+            lines.push(Line::Rule {
+                width: table_width(&widths, border),
+            });
+        }
+        Ok(Self { lines })
+    }
+
+    /// Write every line of the layout.
+    pub(crate) fn write(
+        &self,
+        writer: &mut dyn fmt::Write,
+        ansi: Option<ColorDepth>,
+    ) -> fmt::Result {
+        self.lines.iter().try_for_each(|l| l.write(writer, ansi))
+    }
+}
+
+impl Line<'_> {
+    /// Write the line, followed by a line break.
+    ///
+    /// When `ansi` is set, cell styles and formatters are applied with colors of that depth, and borders are bold.
+    pub(crate) fn write(
+        &self,
+        writer: &mut dyn fmt::Write,
+        ansi: Option<ColorDepth>,
+    ) -> fmt::Result {
+        match self {
+            Line::Rule { width } => {
+                let rule = "━".repeat(*width);
+                if let Some(depth) = ansi {
+                    writeln!(writer, "{}", BORDER.paint(&rule, depth))
+                } else {
+                    writeln!(writer, "{}", rule)
+                }
+            }
+            Line::Row { left, right, cells } => {
+                let edge = if let Some(depth) = ansi {
+                    BORDER.paint("┃", depth)
+                } else {
+                    "┃".to_string()
+                };
+                if *left {
+                    write!(writer, "{}", edge)?;
+                }
+                for placed in cells {
+                    if let Some(depth) = ansi {
+                        write!(
+                            writer,
+                            "{}",
+                            placed.cell.render_with_width(
+                                placed.width,
+                                placed.fraction,
+                                placed.decoration,
+                                depth
+                            )
+                        )?;
+                    } else {
+                        write!(
+                            writer,
+                            "{}",
+                            placed
+                                .cell
+                                .render_with_width_raw(placed.width, placed.fraction)
+                        )?;
+                    }
+                }
+                if *right {
+                    write!(writer, "{}", edge)?;
+                }
+                writeln!(writer)
+            }
+            Line::Caption(text) => writeln!(writer, "{}", text),
+        }
+    }
+}
+
+/// Total width of a table, borders included.
+pub(crate) fn table_width(widths: &[usize], border: Border) -> usize {
+    widths.iter().map(|v| v + 2).sum::<usize>()
+        + if border.left { 1 } else { 0 }
+        + if border.right { 1 } else { 0 }
+}
+
+/// Compute the width of every column from the widths of the cells.
+///
+/// Also returns the widest fraction of the decimal-aligned cells of every column.
+/// Columns are widened so that the widest integer and fraction parts fit together.
+pub(crate) fn measure<'a>(
+    rows: impl IntoIterator<Item = &'a [Cell]>,
+    columns: usize,
+) -> Result<(Vec<usize>, Vec<usize>), TableError> {
+    let mut v = vec![0; columns];
+    let mut integers = vec![0; columns];
+    let mut fractions = vec![0; columns];
+    for row in rows {
+        for (index, cell) in row.iter().enumerate() {
+            if let Some(c) = v.get_mut(index) {
+                if let Some(iw) = cell.get_width() {
+                    if iw > *c {
+                        *c = iw;
+                    }
+                }
+                if let Some((integer, fraction)) = cell.decimal_parts() {
+                    integers[index] = integers[index].max(integer);
+                    fractions[index] = fractions[index].max(fraction);
+                }
+            } else {
+                Err(TableError::Unexpected(
+                    "Update width failed(index error)".to_string(),
+                ))?
+            }
+        }
+    }
+    for (index, c) in v.iter_mut().enumerate() {
+        *c = (*c).max(integers[index] + fractions[index]);
+    }
+    Ok((v, fractions))
+}
+
+/// Place the cells of row `y`, resolving merged cells against the column `widths`.
+///
+/// `fractions` are the fraction widths given by [measure]. Merged cells don't use them.
+pub(crate) fn place_row<'a>(
+    row: &'a [Cell],
+    y: usize,
+    widths: &[usize],
+    fractions: &[usize],
+) -> Result<Vec<Placed<'a>>, TableError> {
+    let mut placed = vec![];
+    let mut x = 0;
+    while let Some(cell) = row.get(x) {
+        let Some(cell_width) = widths.get(x) else {
+            Err(TableError::Unexpected(
+                "Getting width failed(index error)".to_string(),
+            ))?
+        };
+        let merge = cell.get_merge().unwrap_or(0);
+        let width = if merge > 0 {
+            let Some(merged) = row.get((x + 1)..=(x + merge)) else {
+                Err(TableError::MergeOutOfTable {
+                    x,
+                    y,
+                    merge,
+                    max: widths.len() - x - 1,
+                })?
+            };
+            let mut merged_width = 0;
+            for (mx, cm) in merged.iter().enumerate() {
+                if !matches!(cm.get_content(), Content::None) {
+                    Err(TableError::MergeOverwrite { x: x + mx + 1, y })?
+                }
+                if let Some(mw) = widths.get(mx + x + 1) {
+                    merged_width += mw + 2;
+                } else {
+                    Err(TableError::Unexpected(
+                        "Render merge failed(index error)".to_string(),
+                    ))?
+                }
+            }
+            merged_width + cell_width
+        } else {
+            *cell_width
+        };
+        placed.push(Placed {
+            cell,
+            x,
+            decoration: Decoration::NONE,
+            width,
+            fraction: match merge {
+                0 => fractions.get(x).copied().unwrap_or(0),
+                _ => 0,
+            },
+        });
+        x += merge + 1;
+    }
+    Ok(placed)
+}
+
+#[test]
+fn test_layout_merge() {
+    let rows: [Vec<Cell>; 2] = [
+        vec![
+            Cell::default().with_content(Content::new("a")),
+            Cell::default().with_content(Content::new("bbb")),
+            Cell::default().with_content(Content::new("cc")),
+        ],
+        Cell::default()
+            .with_content(Content::new("merged"))
+            .with_span(1)
+            .into_iter()
+            .chain(std::iter::once(Cell::default()))
+            .collect(),
+    ];
+    let rows = rows.iter().map(|r| r.as_slice()).collect::<Vec<_>>();
+    let layout = Layout::build(&rows, 3, &[], Border::ALL, &|_, _, _| Decoration::NONE).unwrap();
+    assert_eq!(layout.lines.len(), 4);
+    if let Line::Row { cells, .. } = &layout.lines[2] {
+        assert_eq!(cells.len(), 2);
+        assert_eq!((cells[0].x, cells[0].width), (0, 6));
+        assert_eq!((cells[1].x, cells[1].width), (2, 2));
+    } else {
+        panic!("expected a row");
+    }
+
+    let mut out = String::new();
+    layout.write(&mut out, None).unwrap();
+    assert_eq!(
+        out,
+        "━━━━━━━━━━━━━━\n┃ a  bbb  cc ┃\n┃ merged     ┃\n━━━━━━━━━━━━━━\n"
+    );
+}
@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::error::TableError;
+use crate::table::{Renderer, Table};
+
+/// A handle redrawing a table in place on an ANSI terminal.
+///
+/// Every call to [update] moves the cursor back to the top of the previous render and only rewrites the lines that changed.
+/// The table must not be wider than the terminal, otherwise wrapped lines break the height computation.
+///
+/// ```rust,no_run
+/// # use table_formatter::table::{LiveTable, Table};
+/// # use table_formatter::cell;
+/// let mut live = LiveTable::new(std::io::stdout());
+/// for tick in 0..10 {
+///     let table = Table::new(vec![vec![cell!("host-1"), cell!(format!("step {}", tick))]]);
+///     live.update(&table).unwrap();
+///     std::thread::sleep(std::time::Duration::from_secs(1));
+/// }
+/// ```
+///
+/// [update]: #method.update
+pub struct LiveTable<W: io::Write> {
+    writer: W,
+    setting: Renderer,
+    lines: Vec<String>,
+}
+
+impl<W: io::Write> LiveTable<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            setting: Renderer::Normal,
+            lines: vec![],
+        }
+    }
+
+    pub fn with_renderer(mut self, setting: Renderer) -> Self {
+        self.setting = setting;
+        self
+    }
+    pub fn set_renderer(&mut self, setting: Renderer) {
+        self.setting = setting;
+    }
+
+    /// Redraw the table, replacing the previous render.
+    pub fn update(&mut self, table: &Table) -> Result<(), TableError> {
+        let rendered = table.to_string_with(self.setting)?;
+        let lines = rendered.lines().map(str::to_string).collect::<Vec<_>>();
+
+        let mut out = String::new();
+        if !self.lines.is_empty() {
+            out += &format!("\r\x1b[{}A", self.lines.len());
+        }
+        let mut skipped = 0;
+        for (index, line) in lines.iter().enumerate() {
+            if self.lines.get(index) == Some(line) {
+                skipped += 1;
+                continue;
+            }
+            if skipped > 0 {
+                out += &format!("\x1b[{}B", skipped);
+                skipped = 0;
+            }
+            out += "\x1b[2K";
+            out += line;
+            out += "\n";
+        }
+        if skipped > 0 {
+            out += &format!("\x1b[{}B", skipped);
+        }
+        if lines.len() < self.lines.len() {
+            out += "\x1b[J";
+        }
+
+        self.writer.write_all(out.as_bytes())?;
+        self.writer.flush()?;
+        self.lines = lines;
+        Ok(())
+    }
+
+    /// Erase the previous render and move the cursor back to where it started.
+    pub fn clear(&mut self) -> Result<(), TableError> {
+        if !self.lines.is_empty() {
+            write!(self.writer, "\r\x1b[{}A\x1b[J", self.lines.len())?;
+            self.writer.flush()?;
+            self.lines.clear();
+        }
+        Ok(())
+    }
+
+    /// Number of lines of the last render.
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[test]
+fn test_live_table() {
+    use crate::cell;
+
+    let table = |status: &str, extra: bool| {
+        let mut rows = vec![
+            vec![cell!("web-1"), cell!("ok")],
+            vec![cell!("web-2"), cell!(status)],
+        ];
+        if extra {
+            rows.push(vec![cell!("web-3"), cell!("ok")]);
+        }
+        Table::new(rows)
+    };
+    let mut live = LiveTable::new(vec![]).with_renderer(Renderer::Raw);
+
+    live.update(&table("ok", false)).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(live.get_ref()),
+        "\x1b[2K web-1  ok \n\x1b[2K web-2  ok \n"
+    );
+    assert_eq!(live.height(), 2);
+
+    live.writer.clear();
+    live.update(&table("no", false)).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(live.get_ref()),
+        "\r\x1b[2A\x1b[1B\x1b[2K web-2  no \n"
+    );
+
+    live.writer.clear();
+    live.update(&table("no", true)).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(live.get_ref()),
+        "\r\x1b[2A\x1b[2B\x1b[2K web-3  ok \n"
+    );
+
+    live.writer.clear();
+    live.update(&table("no", false)).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(live.get_ref()),
+        "\r\x1b[3A\x1b[2B\x1b[J"
+    );
+
+    live.writer.clear();
+    live.clear().unwrap();
+    assert_eq!(String::from_utf8_lossy(live.get_ref()), "\r\x1b[2A\x1b[J");
+    assert_eq!(live.height(), 0);
+}
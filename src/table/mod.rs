@@ -2,28 +2,64 @@
 
 mod cell;
 mod content;
+mod delimited;
+mod edit;
+mod footer;
+#[cfg(feature = "json")]
+mod json;
+mod layout;
+mod live;
+mod page;
+mod parse;
+#[cfg(feature = "serde")]
+mod records;
+mod row;
+mod rule;
+mod scale;
 mod settings;
+mod sort;
+mod stream;
+mod style;
 #[allow(clippy::module_inception)]
 mod table;
+mod value;
+mod writer;
 
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub use cell::*;
 use colored::ColoredString;
 pub use content::*;
+pub use footer::*;
+pub use live::*;
+pub use page::*;
+#[cfg(feature = "serde")]
+pub use records::*;
+pub use row::*;
+pub use rule::*;
+pub use scale::{ColorScale, ScaleMode};
 pub use settings::*;
+pub use sort::*;
+pub use stream::*;
+pub use style::{Color, Style};
 pub use table::*;
+pub use value::*;
+#[cfg(feature = "derive")]
+pub use table_formatter_derive::TableRow;
 
 /// Wrapper for formatting-functions.
 /// 
 /// All functions could be converted to [Normal::Boxed], if you don't care about performance, try [fmt!] macro.
+///
+/// Boxed closures must be `Send + Sync`, so that a [Table] can be built in one thread and rendered in another.
 /// 
 /// [fmt!]: ../macro.fmt.html
 /// [Normal::Boxed]: #variant.Boxed
+/// [Table]: struct.Table.html
 #[derive(Clone)]
 pub enum FormatterFunc {
     Normal(fn(ColoredString) -> ColoredString),
-    Boxed(Rc<Box<dyn Fn(ColoredString) -> ColoredString>>),
+    Boxed(Arc<dyn Fn(ColoredString) -> ColoredString + Send + Sync>),
 }
 
 impl FormatterFunc {
@@ -0,0 +1,170 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::error::TableError;
+use crate::table::{Cell, Table};
+
+use super::layout::measure;
+
+/// How the columns of a page are sized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageWidths {
+    /// Every page has the widths of the whole table, so columns don't move from a page to the next.
+    #[default]
+    Stable,
+    /// Every page is measured on its own rows.
+    PerPage,
+}
+
+/// How [Table::page] splits a table. Pages hold `size` rows, at least one.
+///
+/// [Table::page]: struct.Table.html#method.page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    size: usize,
+    widths: PageWidths,
+    footer: bool,
+}
+
+impl Pagination {
+    pub fn new(size: usize) -> Self {
+        Self {
+            size: size.max(1),
+            widths: PageWidths::Stable,
+            footer: false,
+        }
+    }
+
+    pub fn with_widths(mut self, widths: PageWidths) -> Self {
+        self.widths = widths;
+        self
+    }
+    /// Caption every page with its rows, such as `rows 51–100 of 932`.
+    pub fn with_footer(mut self, footer: bool) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+    pub fn get_widths(&self) -> PageWidths {
+        self.widths
+    }
+    pub fn get_footer(&self) -> bool {
+        self.footer
+    }
+}
+
+impl Table {
+    /// A copy of the table keeping the rows for which `predicate` returns `true`. The header is kept.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// # use table_formatter::cell;
+    /// let table = Table::create(
+    ///     vec![cell!("unit"), cell!("state")],
+    ///     vec![vec![cell!("cron"), cell!("active")], vec![cell!("nginx"), cell!("failed")]],
+    ///     true,
+    /// );
+    /// let failed = table.filter_rows(|row| row[1].get_content().get_text().as_deref() == Some("failed"));
+    /// assert_eq!(
+    ///     failed.to_string_with(Renderer::Raw).unwrap(),
+    ///     " unit   state  \n━━━━━━━━━━━━━━━\n nginx  failed \n"
+    /// );
+    /// ```
+    pub fn filter_rows(&self, mut predicate: impl FnMut(&[Cell]) -> bool) -> Table {
+        let header = self.header.min(self.table.len());
+        let mut table = self.clone();
+        table.table = self.table[..header]
+            .iter()
+            .cloned()
+            .chain(
+                self.table[header..]
+                    .iter()
+                    .filter(|r| predicate(r))
+                    .cloned(),
+            )
+            .collect();
+        table
+    }
+
+    /// A copy of the table keeping the rows in `range`. The header is kept.
+    ///
+    /// The range must be within the rows of the table.
+    pub fn slice_rows(&self, range: impl RangeBounds<usize>) -> Result<Table, TableError> {
+        let rows = self.row_count();
+        let out = |row: usize| TableError::RowOutOfTable { row, rows };
+        let start = match range.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s.checked_add(1).ok_or(out(*s))?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(e) => e.checked_add(1).ok_or(out(*e))?,
+            Bound::Excluded(e) => *e,
+            Bound::Unbounded => rows,
+        };
+        if end > rows {
+            return Err(out(end));
+        }
+        if start > end {
+            return Err(out(start));
+        }
+        let header = self.header.min(self.table.len());
+        let mut table = self.clone();
+        table.table = self.table[..header]
+            .iter()
+            .chain(&self.table[header + start..header + end])
+            .cloned()
+            .collect();
+        Ok(table)
+    }
+
+    /// The number of pages, at least one.
+    pub fn page_count(&self, pagination: &Pagination) -> usize {
+        self.row_count().div_ceil(pagination.size).max(1)
+    }
+
+    /// A copy of the table keeping the rows of page `index`, counted from zero. The header is repeated on every page.
+    ///
+    /// With a footer, the rows of the page are added to the caption of the table, on a line of their own.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Pagination, Renderer, Table};
+    /// # use table_formatter::cell;
+    /// let rows = (1..=5).map(|i| vec![cell!(format!("job-{}", i))]).collect();
+    /// let table = Table::create(vec![cell!("name")], rows, true);
+    /// let pagination = Pagination::new(2).with_footer(true);
+    /// assert_eq!(table.page_count(&pagination), 3);
+    /// assert_eq!(
+    ///     table.page(1, &pagination).unwrap().to_string_with(Renderer::Raw).unwrap(),
+    ///     " name  \n━━━━━━━\n job-3 \n job-4 \nrows 3–4 of 5\n"
+    /// );
+    /// ```
+    pub fn page(&self, index: usize, pagination: &Pagination) -> Result<Table, TableError> {
+        let pages = self.page_count(pagination);
+        if index >= pages {
+            return Err(TableError::PageOutOfTable { page: index, pages });
+        }
+        let rows = self.row_count();
+        let start = (index * pagination.size).min(rows);
+        let end = (start + pagination.size).min(rows);
+        let mut page = self.slice_rows(start..end)?;
+        if pagination.widths == PageWidths::Stable {
+            let columns = self.validate()?;
+            let (widths, _) = measure(self.table.iter().map(|r| r.as_slice()), columns)?;
+            page.min_widths = widths;
+        }
+        if pagination.footer {
+            let position = match end {
+                0 => "rows 0 of 0".to_string(),
+                _ => format!("rows {}–{} of {}", start + 1, end, rows),
+            };
+            page.caption = Some(match page.caption {
+                Some(caption) => format!("{}\n{}", caption, position),
+                None => position,
+            });
+        }
+        Ok(page)
+    }
+}
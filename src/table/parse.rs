@@ -0,0 +1,451 @@
+use crate::error::TableError;
+use crate::table::{Align, Border, Cell, Content, Table};
+
+const VERTICAL: [char; 4] = ['|', '┃', '│', '║'];
+const HORIZONTAL: [char; 5] = ['-', '=', '━', '─', '═'];
+const JUNCTION: [char; 21] = [
+    '+', ':', '┼', '┬', '┴', '├', '┤', '┌', '┐', '└', '┘', '╋', '┳', '┻', '┣', '┫', '╬', '╦', '╩',
+    '╠', '╣',
+];
+
+fn invalid(line: usize, reason: &str) -> TableError {
+    TableError::InvalidTableText {
+        line,
+        reason: reason.to_string(),
+    }
+}
+
+impl Table {
+    /// Parse a GitHub Flavored Markdown pipe table, such as the output of the markdown renderer.
+    ///
+    /// The first row is the header and the delimiter row sets the alignment of every column.
+    /// Both `|` and `┃` separate cells, and `\|` is a literal pipe. Rows with fewer cells than the header are filled with empty cells.
+    /// Cells holding `━━━` are splitters; a splitter row right under the header is the one added by [create].
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Align, Renderer, Table};
+    /// let table = Table::from_markdown("| name | size |\n|:-----|-----:|\n| a.txt | 12 |\n").unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///      " name   size \n━━━━━━━━━━━━━\n a.txt    12 \n"
+    /// );
+    /// ```
+    ///
+    /// [create]: #method.create
+    pub fn from_markdown(text: &str) -> Result<Table, TableError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty());
+        let Some((header_line, header)) = lines.next() else {
+            return Ok(Table::new(vec![]));
+        };
+        let header = split_pipes(header);
+        let Some((line, delimiter)) = lines.next() else {
+            return Err(invalid(header_line + 1, "missing delimiter row"));
+        };
+        let aligns = split_pipes(delimiter)
+            .iter()
+            .map(|d| {
+                let dashes = d.strip_prefix(':').unwrap_or(d);
+                let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+                if dashes.is_empty() || dashes.chars().any(|c| c != '-') {
+                    return Err(invalid(line, "invalid delimiter row"));
+                }
+                Ok(match (d.starts_with(':'), d.ends_with(':')) {
+                    (true, true) => Align::Center,
+                    (false, true) => Align::Right,
+                    _ => Align::Left,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if aligns.len() != header.len() {
+            return Err(TableError::InvalidRecordLength {
+                line,
+                expected: header.len(),
+                actual: aligns.len(),
+            });
+        }
+
+        let cells = |row: Vec<String>| {
+            row.into_iter()
+                .zip(aligns.iter())
+                .map(|(text, align)| {
+                    let content = match text.as_str() {
+                        "━━━" => Content::Splitter,
+                        _ => Content::Text(text),
+                    };
+                    Cell::default().with_content(content).with_align(*align)
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut rows = vec![];
+        for (line, row) in lines {
+            let mut row = split_pipes(row);
+            if row.len() < header.len() {
+                row.resize(header.len(), String::new());
+            }
+            if row.len() != header.len() {
+                return Err(TableError::InvalidRecordLength {
+                    line,
+                    expected: header.len(),
+                    actual: row.len(),
+                });
+            }
+            rows.push(cells(row));
+        }
+        let splitter = |row: &Vec<Cell>| {
+            row.iter()
+                .all(|c| matches!(c.get_content(), Content::Splitter))
+        };
+        if rows.first().is_some_and(splitter) {
+            rows.remove(0);
+        }
+        Ok(Table::create(cells(header), rows, true))
+    }
+
+    /// Parse a table drawn with box characters, such as the output of [render_raw].
+    ///
+    /// Horizontal rules use `-`, `=`, `━`, `─` or `═`, and columns are separated by `|`, `┃`, `│` or `║`.
+    /// Without inner separators, like in this crate's output, columns are split on the gaps of at least two spaces between the cells.
+    ///
+    /// - The borders of the table are recovered, and a rule after the first row makes it the header.
+    /// - Alignment is guessed from the spaces around the text of the cells.
+    /// - With inner separators, a missing separator merges the cells around it. Without them, text over the gap between two columns does.
+    /// - Without inner separators, columns whose cells are all empty are recovered from the extra spaces they leave.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// let text = "+-----+---------+\n| id  | name    |\n+=====+=========+\n|   1 | lint    |\n| both          |\n+-----+---------+\n";
+    /// let table = Table::from_box(text).unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///     "━━━━━━━━━━━━\n┃ id  name ┃\n┃━━━━━━━━━━┃\n┃  1  lint ┃\n┃ both     ┃\n━━━━━━━━━━━━\n"
+    /// );
+    /// ```
+    ///
+    /// [render_raw]: #method.render_raw
+    pub fn from_box(text: &str) -> Result<Table, TableError> {
+        // Rows keep the length of their trailing spaces, which may be empty columns.
+        let lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let len = l.trim_end_matches('\r').chars().count();
+                (i + 1, l.trim_end().chars().collect::<Vec<_>>(), len)
+            })
+            .filter(|(_, l, _)| !l.is_empty())
+            .collect::<Vec<_>>();
+        let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+            return Ok(Table::new(vec![]));
+        };
+        let top = is_rule(&first.1);
+        let bottom = lines.len() > 1 && is_rule(&last.1);
+        let inner = &lines[top as usize..lines.len() - bottom as usize];
+
+        let mut rows: Vec<(usize, &[char], usize)> = vec![];
+        let mut header = false;
+        for (line, chars, len) in inner {
+            if !is_rule(chars) {
+                rows.push((*line, chars, *len));
+            } else if rows.len() == 1 {
+                header = true;
+            }
+        }
+        let Some(&(_, first_row, _)) = rows.first() else {
+            return Ok(Table::new(vec![]));
+        };
+
+        let edge = |c: Option<&char>| c.is_some_and(|c| VERTICAL.contains(c));
+        let (left, right) = (edge(first_row.first()), edge(first_row.last()));
+        let mut grid = vec![];
+        for &(line, chars, _) in rows.iter() {
+            if edge(chars.first()) != left || edge(chars.last()) != right {
+                return Err(invalid(line, "inconsistent border"));
+            }
+            let end = chars.len() - right as usize;
+            grid.push(&chars[(left as usize).min(end)..end]);
+        }
+        // Without right border, the trailing spaces shared by every row are kept.
+        let shared = match right {
+            true => 0,
+            false => rows.iter().map(|r| r.2).min().unwrap_or(0) - left as usize,
+        };
+        let width = grid.iter().map(|r| r.len()).max().unwrap_or(0).max(shared);
+        let grid = grid
+            .into_iter()
+            .map(|r| {
+                let mut r = r.to_vec();
+                r.resize(width, ' ');
+                r
+            })
+            .collect::<Vec<_>>();
+
+        // Column separators are the positions holding a vertical bar in at least half of the rows.
+        let separators = (0..width)
+            .filter(|&x| {
+                let count = grid.iter().filter(|r| VERTICAL.contains(&r[x])).count();
+                count > 0 && count * 2 >= grid.len()
+            })
+            .collect::<Vec<_>>();
+        let (slots, padding) = if separators.is_empty() {
+            (gaps(&grid, width), 0)
+        } else {
+            let mut slots = vec![];
+            let mut start = 0;
+            for &x in separators.iter().chain(std::iter::once(&width)) {
+                slots.push((start, x));
+                start = x + 1;
+            }
+            (slots, 1)
+        };
+
+        // Split every row into `(text, alignment, span)`.
+        let parsed = grid
+            .iter()
+            .map(|row| {
+                let mut cells = vec![];
+                let mut index = 0;
+                while index < slots.len() {
+                    let start = slots[index].0;
+                    let mut span = 0;
+                    while index + span + 1 < slots.len()
+                        && match padding {
+                            0 => crosses(row, slots[index + span].1, slots[index + span + 1].0),
+                            _ => !VERTICAL.contains(&row[slots[index + span].1]),
+                        }
+                    {
+                        span += 1;
+                    }
+                    let end = slots[index + span].1;
+                    let slot = &row[start..end];
+                    cells.push((slot_text(slot), slot_align(slot, padding), span));
+                    index += span + 1;
+                }
+                cells
+            })
+            .collect::<Vec<_>>();
+
+        // The alignment most cells of a column agree on.
+        let columns = (0..slots.len())
+            .map(|x| {
+                let mut votes = [0; 3];
+                for row in parsed.iter().skip(header as usize) {
+                    let mut column = 0;
+                    for (_, align, span) in row {
+                        if column == x && *span == 0 {
+                            match align {
+                                Some(Align::Left) => votes[0] += 1,
+                                Some(Align::Center) => votes[1] += 1,
+                                Some(Align::Right) => votes[2] += 1,
+                                _ => {}
+                            }
+                        }
+                        column += span + 1;
+                    }
+                }
+                match votes.iter().enumerate().max_by_key(|(i, v)| (**v, 3 - i)) {
+                    Some((1, v)) if *v > 0 => Align::Center,
+                    Some((2, v)) if *v > 0 => Align::Right,
+                    _ => Align::Left,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut table = parsed
+            .into_iter()
+            .map(|row| {
+                let mut cells = vec![];
+                for (text, align, span) in row {
+                    let align = align.unwrap_or(columns[cells.len()]);
+                    let cell = Cell::default()
+                        .with_content(Content::Text(text))
+                        .with_align(align);
+                    if span > 0 {
+                        cells.extend(cell.with_span(span));
+                    } else {
+                        cells.push(cell);
+                    }
+                }
+                cells
+            })
+            .collect::<Vec<_>>();
+        let table = if header {
+            let header = table.remove(0);
+            Table::create(header, table, true)
+        } else {
+            Table::new(table)
+        };
+        Ok(table.with_border(Border::new(left, right, top, bottom)))
+    }
+}
+
+/// Split a markdown row on the pipes which are not escaped, dropping the outer ones.
+fn split_pipes(line: &str) -> Vec<String> {
+    let line = line.strip_prefix(['|', '┃']).unwrap_or(line);
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(p @ ('|' | '┃')) => cell.push(p),
+                Some(other) => {
+                    cell.push('\\');
+                    cell.push(other);
+                }
+                None => cell.push('\\'),
+            },
+            '|' | '┃' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            c => cell.push(c),
+        }
+    }
+    if !cell.trim().is_empty() || cells.is_empty() {
+        cells.push(cell.trim().to_string());
+    }
+    cells
+}
+
+fn is_rule(line: &[char]) -> bool {
+    line.iter().any(|c| HORIZONTAL.contains(c))
+        && line
+            .iter()
+            .all(|c| HORIZONTAL.contains(c) || VERTICAL.contains(c) || JUNCTION.contains(c))
+}
+
+/// Columns of a table without separators: the text between runs of at least two spaces shared by the rows.
+///
+/// Merged cells write over the gaps of the columns they cover, so the columns are found on the rows with the most cells,
+/// and on the rows which keep as many columns once added to them.
+fn gaps(grid: &[Vec<char>], width: usize) -> Vec<(usize, usize)> {
+    let blanks = grid
+        .iter()
+        .map(|r| r.iter().map(|c| *c == ' ').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let most = blanks.iter().map(|b| slots(b).len()).max().unwrap_or(0);
+    let mut blank = vec![true; width];
+    for b in blanks.iter().filter(|b| slots(b).len() == most) {
+        blank.iter_mut().zip(b).for_each(|(x, b)| *x &= b);
+    }
+    let count = slots(&blank).len();
+    for b in blanks.iter() {
+        let joined = blank
+            .iter()
+            .zip(b)
+            .map(|(x, b)| *x && *b)
+            .collect::<Vec<_>>();
+        if slots(&joined).len() == count {
+            blank = joined;
+        }
+    }
+    let mut slots = slots(&blank);
+    // A gap written over by most rows is a space in the text of a column rather than between columns.
+    let mut i = 0;
+    while i + 1 < slots.len() {
+        let crossing = grid
+            .iter()
+            .filter(|r| crosses(r, slots[i].1, slots[i + 1].0))
+            .count();
+        if crossing * 2 > grid.len() {
+            slots[i].1 = slots.remove(i + 1).1;
+        } else {
+            i += 1;
+        }
+    }
+    // The last column ends with the longest row, which may be one left out above.
+    let end = grid
+        .iter()
+        .filter_map(|r| r.iter().rposition(|c| *c != ' '));
+    if let (Some(last), Some(end)) = (slots.last_mut(), end.max()) {
+        last.1 = last.1.max(end + 1);
+    }
+    // A column whose cells are all empty is two spaces wide, so every extra pair of spaces in a gap is such a column.
+    // The text of a column starts after a one-space gutter and ends before one.
+    let mut columns = vec![];
+    let mut from = 0;
+    for slot in slots.into_iter().map(Some).chain([None]) {
+        let to = slot.map_or(width, |(start, _)| start.saturating_sub(1));
+        for blank in 0..to.saturating_sub(from) / 2 {
+            let x = from + 1 + 2 * blank;
+            columns.push((x, x));
+        }
+        if let Some(slot) = slot {
+            columns.push(slot);
+            from = slot.1 + 1;
+        }
+    }
+    columns
+}
+
+/// Whether `row` has text in `from..to`, such as a merged cell over the gap between two columns.
+fn crosses(row: &[char], from: usize, to: usize) -> bool {
+    row[from..to].iter().any(|c| *c != ' ')
+}
+
+/// The text between the runs of at least two `blank` positions.
+fn slots(blank: &[bool]) -> Vec<(usize, usize)> {
+    let width = blank.len();
+    let mut slots = vec![];
+    let mut x = 0;
+    while x < width {
+        if blank[x] {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        // A single blank position belongs to the text, like the space in "hello world".
+        while x < width && !(blank[x] && (x + 1 == width || blank[x + 1])) {
+            x += 1;
+        }
+        slots.push((start, x));
+    }
+    slots
+}
+
+fn slot_text(slot: &[char]) -> String {
+    let text = slot
+        .iter()
+        .map(|c| if VERTICAL.contains(c) { ' ' } else { *c })
+        .collect::<String>();
+    text.trim().to_string()
+}
+
+/// Guess the alignment of a cell from the spaces around its text, beyond `padding` on both sides.
+fn slot_align(slot: &[char], padding: usize) -> Option<Align> {
+    let lead = slot.iter().take_while(|c| **c == ' ').count();
+    if lead == slot.len() {
+        return None;
+    }
+    let trail = slot.iter().rev().take_while(|c| **c == ' ').count();
+    match (lead.saturating_sub(padding), trail.saturating_sub(padding)) {
+        (0, 0) => None,
+        (0, _) => Some(Align::Left),
+        (_, 0) => Some(Align::Right),
+        (l, t) if l.abs_diff(t) <= 1 => Some(Align::Center),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_parse_helpers() {
+    assert_eq!(split_pipes("| a | b\\|c |"), vec!["a", "b|c"]);
+    assert_eq!(split_pipes("┃a┃┃"), vec!["a", ""]);
+    assert_eq!(split_pipes("a | b"), vec!["a", "b"]);
+    assert!(is_rule(&"+--+==+".chars().collect::<Vec<_>>()));
+    assert!(is_rule(&"┃━━━━┃".chars().collect::<Vec<_>>()));
+    assert!(!is_rule(&"| -- |".chars().collect::<Vec<_>>()));
+
+    let grid =
+        [" a     hello world ", " bbb   x           "].map(|r| r.chars().collect::<Vec<_>>());
+    assert_eq!(gaps(&grid, 19), vec![(1, 4), (7, 18)]);
+    let grid = [" a  b  c  ", " merged c "].map(|r| r.chars().collect::<Vec<_>>());
+    assert_eq!(gaps(&grid, 10), vec![(1, 2), (4, 5), (7, 9)]);
+    let grid = [" a      c ", " d      f "].map(|r| r.chars().collect::<Vec<_>>());
+    assert_eq!(gaps(&grid, 10), vec![(1, 2), (4, 4), (6, 6), (8, 9)]);
+    let slot = |s: &str| s.chars().collect::<Vec<_>>();
+    assert_eq!(slot_align(&slot(" ab   "), 1), Some(Align::Left));
+    assert_eq!(slot_align(&slot("   ab "), 1), Some(Align::Right));
+    assert_eq!(slot_align(&slot("  ab  "), 1), Some(Align::Center));
+    assert_eq!(slot_align(&slot(" ab "), 1), None);
+}
@@ -0,0 +1,200 @@
+use serde::Serialize;
+use serde_json::Value as Json;
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Table, Value};
+
+/// Which fields of the rows become columns, in which order and under which names. See [Table::from_serialize].
+///
+/// Nested fields are named with their path, such as `owner.name`.
+///
+/// [Table::from_serialize]: struct.Table.html#method.from_serialize
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fields {
+    order: Vec<String>,
+    renames: Vec<(String, String)>,
+    skipped: Vec<String>,
+}
+
+impl Fields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Put these fields first, in this order. The other fields follow in their serialization order.
+    pub fn with_order(mut self, fields: Vec<String>) -> Self {
+        self.order = fields;
+        self
+    }
+    /// Show `field` under the header `name`.
+    pub fn with_rename(mut self, field: impl ToString, name: impl ToString) -> Self {
+        self.renames.push((field.to_string(), name.to_string()));
+        self
+    }
+    /// Leave `field` out. Skipping a nested struct skips all of its fields.
+    pub fn with_skip(mut self, field: impl ToString) -> Self {
+        self.skipped.push(field.to_string());
+        self
+    }
+
+    fn is_skipped(&self, field: &str) -> bool {
+        self.skipped.iter().any(|s| {
+            field == s
+                || field
+                    .strip_prefix(s.as_str())
+                    .is_some_and(|r| r.starts_with('.'))
+        })
+    }
+
+    fn header(&self, field: &str) -> String {
+        self.renames
+            .iter()
+            .find(|(f, _)| f == field)
+            .map_or(field, |(_, name)| name)
+            .to_string()
+    }
+}
+
+impl Table {
+    /// Build a table from serializable rows, one column per field. Requires the `serde` feature.
+    ///
+    /// Numbers, booleans and missing values become typed [Value]s, so numbers are right-aligned.
+    /// Sequences are joined with `, `, and the arrays and objects inside them are shown as compact JSON.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Fields, Renderer, Table};
+    /// #[derive(serde::Serialize)]
+    /// struct Owner {
+    ///     name: String,
+    /// }
+    /// #[derive(serde::Serialize)]
+    /// struct Repo {
+    ///     id: u32,
+    ///     stars: u32,
+    ///     owner: Owner,
+    /// }
+    ///
+    /// let repos = vec![Repo { id: 1, stars: 42, owner: Owner { name: "ferris".to_string() } }];
+    /// let fields = Fields::new()
+    ///     .with_skip("id")
+    ///     .with_order(vec!["owner.name".to_string()])
+    ///     .with_rename("owner.name", "owner");
+    /// let table = Table::from_serialize(&repos, &fields).unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///     " owner   stars \n━━━━━━━━━━━━━━━\n ferris     42 \n"
+    /// );
+    /// ```
+    ///
+    /// [Value]: enum.Value.html
+    pub fn from_serialize<T: Serialize>(rows: &[T], fields: &Fields) -> Result<Table, TableError> {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let json = serde_json::to_value(row)
+                    .map_err(|e| TableError::SerializeError(e.to_string()))?;
+                let mut flat = vec![];
+                flatten(String::new(), json, &mut flat);
+                Ok(flat)
+            })
+            .collect::<Result<Vec<_>, TableError>>()?;
+
+        let mut columns: Vec<String> = vec![];
+        for (name, _) in rows.iter().flatten() {
+            if !columns.contains(name) {
+                columns.push(name.clone());
+            }
+        }
+        columns.retain(|c| !fields.is_skipped(c));
+        // Stable, so the fields which are not listed keep their order.
+        columns.sort_by_key(|c| {
+            fields
+                .order
+                .iter()
+                .position(|o| o == c)
+                .unwrap_or(fields.order.len())
+        });
+
+        let header = columns
+            .iter()
+            .map(|c| Cell::default().with_content(Content::new(fields.header(c))))
+            .collect();
+        let cells = rows
+            .into_iter()
+            .map(|mut row| {
+                columns
+                    .iter()
+                    .map(|c| {
+                        let content = match row.iter().position(|(name, _)| name == c) {
+                            Some(index) => row.swap_remove(index).1,
+                            None => Content::value(Value::Null),
+                        };
+                        Cell::default().with_content(content)
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Table::create(header, cells, true))
+    }
+}
+
+/// Flatten `json` into `(path, content)` pairs, joining nested field names with dots.
+fn flatten(path: String, json: Json, out: &mut Vec<(String, Content)>) {
+    match json {
+        Json::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let path = if path.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten(path, value, out);
+            }
+        }
+        json => out.push((path, content(json))),
+    }
+}
+
+/// The content of a field: sequences are joined, and the arrays and objects inside them are compact JSON like in [Table::from_json].
+fn content(json: Json) -> Content {
+    match json {
+        Json::Array(items) => Content::Text(
+            items
+                .iter()
+                .map(|item| super::json::content(item).get_text().unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        json => super::json::content(&json),
+    }
+}
+
+#[test]
+fn test_fields() {
+    let fields = Fields::new().with_skip("owner").with_rename("id", "ID");
+    assert!(fields.is_skipped("owner"));
+    assert!(fields.is_skipped("owner.name"));
+    assert!(!fields.is_skipped("owners"));
+    assert_eq!(fields.header("id"), "ID");
+    assert_eq!(fields.header("name"), "name");
+
+    let mut out = vec![];
+    flatten(
+        String::new(),
+        serde_json::json!({"a": {"b": 1, "c": [true, "x", {"y": [2]}]}, "d": null, "e": {}}),
+        &mut out,
+    );
+    let out = out
+        .into_iter()
+        .map(|(name, c)| (name, c.get_text().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        out,
+        vec![
+            ("a.b".to_string(), "1".to_string()),
+            ("a.c".to_string(), "true, x, {\"y\":[2]}".to_string()),
+            ("d".to_string(), "".to_string()),
+            ("e".to_string(), "{}".to_string()),
+        ]
+    );
+}
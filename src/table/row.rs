@@ -0,0 +1,55 @@
+use crate::table::{Cell, Table};
+
+/// A type whose values are rows of a table, see [Table::from_rows].
+///
+/// With the `derive` feature, `#[derive(TableRow)]` implements it from the fields of a struct.
+/// Every field becomes a column showing its [Display] output, configured by a `#[table(...)]` attribute:
+///
+/// - `title = "..."`: the header, the field name by default.
+/// - `align = "left" | "center" | "right" | "decimal"`.
+/// - `separator = ','`: the decimal separator of `align = "decimal"`, `'.'` by default.
+/// - `width = 10`: the width of the column.
+/// - `overflow = "ellipsis" | "hidden"`.
+/// - `formatter = path::to::function`: a `fn(ColoredString) -> ColoredString` applied to the field's cells.
+/// - `skip`: leave the field out.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use table_formatter::table::{Renderer, Table, TableRow};
+///
+/// #[derive(TableRow)]
+/// struct Job {
+///     #[table(title = "Job", width = 6, overflow = "hidden")]
+///     name: String,
+///     #[table(align = "right")]
+///     retries: u32,
+///     #[table(skip)]
+///     id: u64,
+/// }
+///
+/// let jobs = vec![Job { name: "compile".to_string(), retries: 2, id: 7 }];
+/// assert_eq!(
+///     Table::from_rows(&jobs).to_string_with(Renderer::Raw).unwrap(),
+///     " Job     retries \n━━━━━━━━━━━━━━━━━\n compil        2 \n"
+/// );
+/// # }
+/// ```
+///
+/// [Table::from_rows]: struct.Table.html#method.from_rows
+/// [Display]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+pub trait TableRow {
+    /// The header cells.
+    fn header() -> Vec<Cell>;
+    /// The cells of this row.
+    fn row(&self) -> Vec<Cell>;
+}
+
+impl Table {
+    /// Create a table from rows of a [TableRow] type, with the header it defines and a splitter.
+    ///
+    /// [TableRow]: trait.TableRow.html
+    pub fn from_rows<T: TableRow>(items: &[T]) -> Table {
+        Table::create(T::header(), items.iter().map(T::row).collect(), true)
+    }
+}
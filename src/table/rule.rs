@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::table::{Content, Style};
+
+/// A condition on the text of a cell.
+///
+/// Numeric predicates use the number of typed [Value]s, and otherwise parse the trimmed text, ignoring `,` thousands separators.
+/// Cells which are not numbers never match them.
+///
+/// [Value]: enum.Value.html
+#[derive(Clone)]
+pub enum Predicate {
+    LessThan(f64),
+    GreaterThan(f64),
+    Equals(String),
+    /// The text is one of the strings.
+    In(Vec<String>),
+    /// The text matches the regular expression. Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    Matches(regex::Regex),
+    Custom(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl Predicate {
+    pub fn test(&self, text: &str) -> bool {
+        self.test_with(text, parse_number(text))
+    }
+
+    /// Test the predicate on a cell whose text is `text` and whose number is `number`.
+    pub(crate) fn test_with(&self, text: &str, number: Option<f64>) -> bool {
+        match self {
+            Predicate::LessThan(v) => number.is_some_and(|n| n < *v),
+            Predicate::GreaterThan(v) => number.is_some_and(|n| n > *v),
+            Predicate::Equals(s) => text == s,
+            Predicate::In(set) => set.iter().any(|s| s == text),
+            #[cfg(feature = "regex")]
+            Predicate::Matches(re) => re.is_match(text),
+            Predicate::Custom(f) => f(text),
+        }
+    }
+}
+
+/// Parse the text of a cell as a number.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    text.trim().replace(',', "").parse().ok()
+}
+
+/// Conditional formatting: a style applied at render time to the cells matching a predicate.
+///
+/// Rules only look at data rows, so the header created by [Table::create] is left alone.
+/// The style of a matching rule is layered on top of the cell's own [Style], and the cell formatters still run last.
+///
+/// ```rust
+/// # use table_formatter::table::{Color, Predicate, Rule, Style, Table};
+/// # use table_formatter::cell;
+/// let table = Table::create(
+///     vec![cell!("host"), cell!("load")],
+///     vec![vec![cell!("web-1"), cell!("-0.5")], vec![cell!("web-2"), cell!("OK")]],
+///     true,
+/// )
+/// .with_rule(Rule::new(Predicate::LessThan(0.0), Style::new().foreground(Color::Red)).on_column(1))
+/// .with_rule(Rule::new(Predicate::Equals("OK".to_string()), Style::new().foreground(Color::Green)));
+/// ```
+///
+/// [Table::create]: struct.Table.html#method.create
+#[derive(Clone)]
+pub struct Rule {
+    predicate: Predicate,
+    style: Style,
+    column: Option<usize>,
+}
+
+impl Rule {
+    /// Create a rule applying to the whole table.
+    pub fn new(predicate: Predicate, style: Style) -> Self {
+        Self {
+            predicate,
+            style,
+            column: None,
+        }
+    }
+
+    /// Restrict the rule to a single column.
+    pub fn on_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn get_column(&self) -> Option<usize> {
+        self.column
+    }
+    pub fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Return the style of the rule if it applies to the content in column `x`.
+    pub(crate) fn style_for(&self, x: usize, content: &Content) -> Option<Style> {
+        let text = content.get_text()?;
+        (self.column.is_none_or(|c| c == x)
+            && self.predicate.test_with(&text, content.get_number()))
+        .then_some(self.style)
+    }
+}
+
+#[test]
+fn test_predicate() {
+    assert!(Predicate::LessThan(0.0).test(" -1,000.5 "));
+    assert!(!Predicate::LessThan(0.0).test("-"));
+    assert!(Predicate::GreaterThan(1.0).test("2"));
+    assert!(Predicate::Equals("OK".to_string()).test("OK"));
+    assert!(!Predicate::Equals("OK".to_string()).test("ok"));
+    assert!(Predicate::In(vec!["a".to_string(), "b".to_string()]).test("b"));
+    assert!(Predicate::Custom(Arc::new(|s| s.len() == 3)).test("abc"));
+    #[cfg(feature = "regex")]
+    assert!(Predicate::Matches(regex::Regex::new("^v[0-9]+$").unwrap()).test("v12"));
+
+    let rule = Rule::new(Predicate::Equals("x".to_string()), Style::new().bold()).on_column(1);
+    assert_eq!(rule.style_for(0, &Content::new("x")), None);
+    assert_eq!(
+        rule.style_for(1, &Content::new("x")),
+        Some(Style::new().bold())
+    );
+    let rule = Rule::new(Predicate::GreaterThan(1000.0), Style::new().bold());
+    let format = crate::table::ValueFormat::default().with_thousands(Some('.'));
+    let content = Content::Value(crate::table::Value::Integer(1500), format);
+    assert_eq!(content.get_text().unwrap(), "1.500");
+    assert_eq!(rule.style_for(0, &content), Some(Style::new().bold()));
+}
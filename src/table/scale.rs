@@ -0,0 +1,154 @@
+use crate::table::Color;
+
+/// How the values of a column are placed on a [ColorScale].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScaleMode {
+    /// Proportionally to the value, between the minimum and the maximum of the column.
+    #[default]
+    Linear,
+    /// By the rank of the value among the distinct values of the column.
+    Rank,
+}
+
+/// A heatmap: a gradient background applied to the numeric cells of a column.
+///
+/// The minimum and maximum are computed from the numeric data cells of the column when the table renders;
+/// other cells are left alone. Named colors are interpolated through their usual xterm values.
+///
+/// When colors are disabled, nothing is painted.
+///
+/// ```rust
+/// # use table_formatter::table::{Color, ColorScale, ScaleMode, Table};
+/// # use table_formatter::cell;
+/// let table = Table::create(
+///     vec![cell!("bench"), cell!("ms")],
+///     vec![vec![cell!("a"), cell!(12.5)], vec![cell!("b"), cell!(30)]],
+///     true,
+/// )
+/// .with_color_scale(
+///     ColorScale::three(1, Color::Green, Color::Yellow, Color::Red).with_mode(ScaleMode::Rank),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorScale {
+    column: usize,
+    stops: Vec<Color>,
+    mode: ScaleMode,
+}
+
+impl ColorScale {
+    /// A gradient from `low` for the smallest value to `high` for the largest.
+    pub fn two(column: usize, low: Color, high: Color) -> Self {
+        Self {
+            column,
+            stops: vec![low, high],
+            mode: ScaleMode::Linear,
+        }
+    }
+
+    /// A gradient from `low` through `mid` to `high`.
+    pub fn three(column: usize, low: Color, mid: Color, high: Color) -> Self {
+        Self {
+            column,
+            stops: vec![low, mid, high],
+            mode: ScaleMode::Linear,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: ScaleMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+    pub fn get_mode(&self) -> ScaleMode {
+        self.mode
+    }
+
+    /// Prepare the scale for the values found in its column.
+    pub(crate) fn prepare(&self, mut values: Vec<f64>) -> PreparedScale<'_> {
+        values.retain(|v| v.is_finite());
+        values.sort_by(f64::total_cmp);
+        values.dedup();
+        PreparedScale {
+            scale: self,
+            values,
+        }
+    }
+}
+
+/// A [ColorScale] with the distinct values of its column, sorted.
+pub(crate) struct PreparedScale<'s> {
+    scale: &'s ColorScale,
+    values: Vec<f64>,
+}
+
+impl PreparedScale<'_> {
+    pub(crate) fn column(&self) -> usize {
+        self.scale.column
+    }
+
+    /// The color of `value`, or `None` if it is not part of the column. Values such as `NaN` are never part of it.
+    pub(crate) fn color(&self, value: f64) -> Option<Color> {
+        if !value.is_finite() {
+            return None;
+        }
+        let (first, last) = (*self.values.first()?, *self.values.last()?);
+        let t = match self.scale.mode {
+            _ if first == last => 0.5,
+            ScaleMode::Linear => (value - first) / (last - first),
+            ScaleMode::Rank => {
+                let rank = self.values.binary_search_by(|v| v.total_cmp(&value)).ok()?;
+                rank as f64 / (self.values.len() - 1) as f64
+            }
+        };
+        let t = t.clamp(0.0, 1.0);
+        let segments = self.scale.stops.len() - 1;
+        let index = ((t * segments as f64) as usize).min(segments - 1);
+        let local = t * segments as f64 - index as f64;
+        Some(lerp(
+            self.scale.stops[index],
+            self.scale.stops[index + 1],
+            local,
+        ))
+    }
+}
+
+fn lerp(from: Color, to: Color, t: f64) -> Color {
+    let (a, b) = (from.rgb(), to.rgb());
+    let mix = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * t).round() as u8;
+    Color::Rgb(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+#[test]
+fn test_color_scale() {
+    let scale = ColorScale::two(0, Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0));
+    let prepared = scale.prepare(vec![10.0, 0.0, 5.0, 1.0]);
+    assert_eq!(prepared.color(0.0), Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(prepared.color(5.0), Some(Color::Rgb(100, 50, 0)));
+    assert_eq!(prepared.color(10.0), Some(Color::Rgb(200, 100, 0)));
+
+    let scale = scale.with_mode(ScaleMode::Rank);
+    let prepared = scale.prepare(vec![10.0, 0.0, 5.0, 1.0, 5.0]);
+    assert_eq!(prepared.color(1.0), Some(Color::Rgb(67, 33, 0)));
+    assert_eq!(prepared.color(5.0), Some(Color::Rgb(133, 67, 0)));
+    assert_eq!(prepared.color(2.0), None);
+
+    let scale = ColorScale::three(
+        0,
+        Color::Rgb(0, 0, 0),
+        Color::Rgb(100, 100, 100),
+        Color::Rgb(100, 0, 0),
+    );
+    let prepared = scale.prepare(vec![0.0, 4.0]);
+    assert_eq!(prepared.color(1.0), Some(Color::Rgb(50, 50, 50)));
+    assert_eq!(prepared.color(2.0), Some(Color::Rgb(100, 100, 100)));
+    assert_eq!(prepared.color(3.0), Some(Color::Rgb(100, 50, 50)));
+    assert_eq!(scale.prepare(vec![]).color(1.0), None);
+    let prepared = scale.prepare(vec![f64::NAN, 0.0, f64::INFINITY, 4.0]);
+    assert_eq!(prepared.color(2.0), Some(Color::Rgb(100, 100, 100)));
+    assert_eq!(prepared.color(f64::NAN), None);
+    assert_eq!(prepared.color(f64::NEG_INFINITY), None);
+}
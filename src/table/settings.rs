@@ -3,30 +3,38 @@
 /// `Overflow::Ellipsis`: "hello world" -> "he..."
 ///
 /// `Overflow::Hidden`: "hello" -> "hello"
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Overflow {
+    #[default]
     Ellipsis,
     Hidden,
 }
 
-impl Default for Overflow {
-    fn default() -> Self {
-        Self::Ellipsis
-    }
-}
-
 /// Text alignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Align {
+    #[default]
     Left,
     Center,
     Right,
+    /// Line up the decimal separators of the column, given as the character.
+    /// Cells without a separator end where it would be, and truncated cells are right-aligned.
+    Decimal(char),
 }
 
-impl Default for Align {
-    fn default() -> Self {
-        Self::Left
-    }
+/// Which part of a cell its style and formatters apply to.
+///
+/// `StyleScope::Content`: only the text, `"  [hello]  "`
+///
+/// `StyleScope::Aligned`: the text and its alignment fill, `" [ hello ] "`
+///
+/// `StyleScope::Cell`: the whole cell box, gutters and padding included, `"[  hello  ]"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StyleScope {
+    Content,
+    Aligned,
+    #[default]
+    Cell,
 }
 
 /// Padding around the content.
@@ -69,10 +77,79 @@ impl Border {
     }
 }
 
+/// When the normal renderer emits ANSI styles.
+///
+/// `ColorPolicy::Auto` disables styles when `NO_COLOR` is set to a non-empty value or `CLICOLOR` is `0`,
+/// and forces them when `CLICOLOR_FORCE` is set to anything but `0`.
+/// Otherwise styles are only emitted to terminals, which only the `render_auto` methods can detect:
+/// other outputs, such as a `String` or a `Vec<u8>`, are never treated as terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorPolicy {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorPolicy {
+    /// Decide whether to emit styles to an output which is a terminal or not.
+    pub fn should_colorize(self, is_terminal: bool) -> bool {
+        let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+        match self {
+            ColorPolicy::Always => true,
+            ColorPolicy::Never => false,
+            ColorPolicy::Auto => {
+                if var("NO_COLOR").is_some() {
+                    false
+                } else if var("CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+                    true
+                } else if var("CLICOLOR").is_some_and(|v| v == "0") {
+                    false
+                } else {
+                    is_terminal
+                }
+            }
+        }
+    }
+}
+
+/// Colors supported by the output of the normal renderer.
+///
+/// Colors deeper than the output supports are mapped to the nearest supported palette entry.
+/// `ColorDepth::None` drops colors but keeps the other attributes such as bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ColorDepth {
+    None,
+    /// The 16 standard colors.
+    Ansi16,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// 24-bit colors.
+    #[default]
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guess the color depth of the terminal from the `COLORTERM` and `TERM` environment variables.
+    pub fn from_env() -> Self {
+        let var = |name| std::env::var(name).unwrap_or_default();
+        let (colorterm, term) = (var("COLORTERM"), var("TERM"));
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else if term == "dumb" {
+            ColorDepth::None
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
 /// Render settings.
 #[derive(Debug, Clone, Copy)]
 pub enum Renderer {
-    /// Render a normal table, with ansi color settings.
+    /// Render a normal table, with ansi color settings. Styles are only emitted if the [ColorPolicy] allows it.
     Normal,
     /// Render a raw table, containing only contents.
     Raw,
@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::error::TableError;
+use crate::table::{Cell, Content, Table};
+
+use super::edit::merged_at;
+
+/// The direction of a [SortKey].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// A custom comparison of two cells, used by [Compare::Custom].
+///
+/// [Compare::Custom]: enum.Compare.html#variant.Custom
+pub type CompareFn = dyn Fn(&Content, &Content) -> Ordering + Send + Sync;
+
+/// How a [SortKey] compares two cells.
+#[derive(Clone, Default)]
+pub enum Compare {
+    /// Compare the text, with runs of digits compared as numbers so that `file2` comes before `file10`.
+    /// Letters are compared without case first. Numeric [Value]s are compared by value and come before text.
+    ///
+    /// [Value]: enum.Value.html
+    #[default]
+    Natural,
+    /// Compare numbers, parsing the text of cells which are not numeric [Value]s.
+    /// Cells which are not numbers always come last, whatever the order.
+    ///
+    /// [Value]: enum.Value.html
+    Numeric,
+    /// Compare with a function, in ascending order.
+    Custom(Arc<CompareFn>),
+}
+
+/// A column to sort on, with its order and comparison. See [Table::sort_by_columns].
+///
+/// [Table::sort_by_columns]: struct.Table.html#method.sort_by_columns
+#[derive(Clone)]
+pub struct SortKey {
+    column: usize,
+    order: SortOrder,
+    compare: Compare,
+}
+
+impl SortKey {
+    /// Sort on `column` in ascending natural order.
+    pub fn new(column: usize) -> Self {
+        Self {
+            column,
+            order: SortOrder::Ascending,
+            compare: Compare::Natural,
+        }
+    }
+
+    pub fn with_order(mut self, order: SortOrder) -> Self {
+        self.order = order;
+        self
+    }
+    pub fn with_compare(mut self, compare: Compare) -> Self {
+        self.compare = compare;
+        self
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+    pub fn get_order(&self) -> SortOrder {
+        self.order
+    }
+
+    fn cmp(&self, a: &Content, b: &Content) -> Ordering {
+        let ordering = match &self.compare {
+            Compare::Natural => natural(a, b),
+            Compare::Numeric => match (a.get_number(), b.get_number()) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => return a.is_none().cmp(&b.is_none()),
+            },
+            Compare::Custom(f) => f(a, b),
+        };
+        match self.order {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+impl Table {
+    /// Sort the rows by the first key, then by the next ones for the rows which are equal.
+    ///
+    /// The header and splitter rows made by [create] stay on top, and rows are moved whole, keeping their merged cells.
+    /// Splitter rows in the body stay in place, and the rows between two of them are sorted on their own.
+    /// The sort is stable. A key on a column covered by a merged cell is an error.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Compare, Renderer, SortKey, SortOrder, Table};
+    /// # use table_formatter::cell;
+    /// let mut table = Table::create(
+    ///     vec![cell!("name"), cell!("cpu")],
+    ///     vec![
+    ///         vec![cell!("sh"), cell!(0.5)],
+    ///         vec![cell!("cargo"), cell!(12)],
+    ///         vec![cell!("bash"), cell!(0.5)],
+    ///     ],
+    ///     true,
+    /// );
+    /// table
+    ///     .sort_by_columns(&[
+    ///         SortKey::new(1).with_order(SortOrder::Descending).with_compare(Compare::Numeric),
+    ///         SortKey::new(0),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     table.to_string_with(Renderer::Raw).unwrap(),
+    ///     " name   cpu \n━━━━━━━━━━━━\n cargo  12  \n bash   0.5 \n sh     0.5 \n"
+    /// );
+    /// ```
+    ///
+    /// [create]: #method.create
+    pub fn sort_by_columns(&mut self, keys: &[SortKey]) -> Result<(), TableError> {
+        let columns = self.validate()?;
+        let header = self.header.min(self.table.len());
+        for key in keys {
+            if key.column >= columns {
+                return Err(TableError::ColumnOutOfTable {
+                    column: key.column,
+                    columns,
+                });
+            }
+            for (y, row) in self.table.iter().enumerate().skip(header) {
+                if merged_at(row, key.column).is_some_and(|start| start < key.column) {
+                    return Err(TableError::SortOnMerged { x: key.column, y });
+                }
+            }
+        }
+        let splitter = |row: &Vec<Cell>| {
+            !row.is_empty()
+                && row
+                    .iter()
+                    .all(|c| matches!(c.get_content(), Content::Splitter))
+        };
+        for group in self.table[header..].split_mut(splitter) {
+            group.sort_by(|a, b| {
+                keys.iter()
+                    .map(|k| k.cmp(a[k.column].get_content(), b[k.column].get_content()))
+                    .fold(Ordering::Equal, Ordering::then)
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Compare two contents in natural order, numeric values first.
+fn natural(a: &Content, b: &Content) -> Ordering {
+    let number = |c: &Content| c.get_number().filter(|_| c.is_number());
+    match (number(a), number(b)) {
+        (Some(x), Some(y)) => return x.total_cmp(&y),
+        (Some(_), None) => return Ordering::Less,
+        (None, Some(_)) => return Ordering::Greater,
+        (None, None) => {}
+    }
+    let (a, b) = (
+        a.get_text().unwrap_or_default(),
+        b.get_text().unwrap_or_default(),
+    );
+    natural_str(&a.to_lowercase(), &b.to_lowercase()).then_with(|| natural_str(&a, &b))
+}
+
+fn natural_str(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(x), Some(y)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        if x.is_ascii_digit() && y.is_ascii_digit() {
+            let (da, ra) = split_digits(a);
+            let (db, rb) = split_digits(b);
+            let (ta, tb) = (da.trim_start_matches('0'), db.trim_start_matches('0'));
+            let ordering = ta.len().cmp(&tb.len()).then_with(|| ta.cmp(tb));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            (a, b) = (ra, rb);
+        } else {
+            if x != y {
+                return x.cmp(&y);
+            }
+            (a, b) = (&a[x.len_utf8()..], &b[y.len_utf8()..]);
+        }
+    }
+}
+
+fn split_digits(s: &str) -> (&str, &str) {
+    s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+}
+
+#[test]
+fn test_natural() {
+    let mut names = vec!["file10", "File2", "file2", "file1", "a", "file02b", ""];
+    names.sort_by(|a, b| natural(&Content::new(a), &Content::new(b)));
+    assert_eq!(
+        names,
+        vec!["", "a", "file1", "File2", "file2", "file02b", "file10"]
+    );
+    assert_eq!(
+        natural(&Content::value(1000), &Content::value(20)),
+        Ordering::Greater
+    );
+    let mut mixed = [
+        Content::new("10"),
+        Content::value(20),
+        Content::new("9"),
+        Content::value(3),
+    ];
+    mixed.sort_by(natural);
+    assert_eq!(
+        mixed
+            .iter()
+            .map(|c| c.get_text().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["3", "20", "9", "10"]
+    );
+
+    let key = SortKey::new(0)
+        .with_order(SortOrder::Descending)
+        .with_compare(Compare::Numeric);
+    assert_eq!(
+        key.cmp(&Content::new("-"), &Content::new("3")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        key.cmp(&Content::new("1,200"), &Content::new("3")),
+        Ordering::Less
+    );
+}
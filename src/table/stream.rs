@@ -0,0 +1,327 @@
+use std::fmt;
+use std::io::{self, IsTerminal};
+
+use crate::error::TableError;
+use crate::table::{Border, Cell, ColorDepth, ColorPolicy, Renderer};
+
+use super::layout::{measure, place_row, table_width, Line};
+use super::style::Decoration;
+use super::table::{header_rows, write_markdown_header, write_markdown_row};
+use super::writer::write_io;
+
+/// How a [TableStream] decides the width of its columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamWidths {
+    /// Use these widths. Wider cells overflow according to their own [Overflow] setting.
+    ///
+    /// As the fractions are unknown, [Align::Decimal] cells are right-aligned.
+    ///
+    /// [Overflow]: ../enum.Overflow.html
+    /// [Align::Decimal]: ../enum.Align.html#variant.Decimal
+    Fixed(Vec<usize>),
+    /// Measure the header and the first `n` rows. Those rows are buffered before anything is written.
+    ///
+    /// Without header, the first row is measured even when `n` is zero.
+    Sampled(usize),
+}
+
+/// A table renderer for row sources too large to be held in a [Table].
+///
+/// Rows are taken from an iterator and written as soon as they arrive.
+/// The header, borders and overflow behave like the buffered renderer.
+///
+/// ```rust
+/// # use table_formatter::table::{Border, StreamWidths, TableStream};
+/// # use table_formatter::cell;
+/// let stream = TableStream::new(StreamWidths::Fixed(vec![4, 6]))
+///     .with_header(vec![cell!("id"), cell!("record")], true)
+///     .with_border(Border::ALL);
+/// let rows = (0..3).map(|i| vec![cell!(i), cell!(format!("log #{}", i))]);
+/// let mut buffer = vec![];
+/// stream.render(rows, &mut buffer).unwrap();
+/// println!("{}", String::from_utf8(buffer).unwrap());
+/// ```
+///
+/// [Table]: ../struct.Table.html
+#[derive(Clone)]
+pub struct TableStream {
+    header: Vec<Vec<Cell>>,
+    widths: StreamWidths,
+    border: Border,
+    color_policy: ColorPolicy,
+    color_depth: ColorDepth,
+}
+
+impl TableStream {
+    pub fn new(widths: StreamWidths) -> Self {
+        Self {
+            header: vec![],
+            widths,
+            border: Border::NONE,
+            color_policy: ColorPolicy::Auto,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Set the header. `splitter` works like in [Table::create].
+    ///
+    /// [Table::create]: ../struct.Table.html#method.create
+    pub fn with_header(mut self, header: Vec<Cell>, splitter: bool) -> Self {
+        self.header = header_rows(header, splitter);
+        self
+    }
+    pub fn with_widths(mut self, widths: StreamWidths) -> Self {
+        self.widths = widths;
+        self
+    }
+    pub fn with_border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+    pub fn set_border(&mut self, border: Border) {
+        self.border = border;
+    }
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Self {
+        self.color_policy = policy;
+        self
+    }
+    pub fn set_color_policy(&mut self, policy: ColorPolicy) {
+        self.color_policy = policy;
+    }
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = depth;
+        self
+    }
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+    }
+
+    /// Render the rows according to the render settings.
+    ///
+    /// See [Table::rendered_by].
+    ///
+    /// [Table::rendered_by]: ../struct.Table.html#method.rendered_by
+    pub fn rendered_by(
+        &self,
+        setting: Renderer,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut impl io::Write,
+    ) -> Result<(), TableError> {
+        write_io(writer, |w| self.render_fmt(setting, rows, w))
+    }
+
+    /// Render the rows with formatting.
+    pub fn render(
+        &self,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut impl io::Write,
+    ) -> Result<(), TableError> {
+        self.rendered_by(Renderer::Normal, rows, writer)
+    }
+
+    /// Render the rows without any formatting.
+    pub fn render_raw(
+        &self,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut impl io::Write,
+    ) -> Result<(), TableError> {
+        self.rendered_by(Renderer::Raw, rows, writer)
+    }
+
+    /// This works like [render], but [ColorPolicy::Auto] checks whether `writer` itself is a terminal.
+    ///
+    /// Other methods can't tell, so they only emit styles when forced.
+    ///
+    /// [render]: #method.render
+    /// [ColorPolicy::Auto]: ../enum.ColorPolicy.html#variant.Auto
+    pub fn render_auto<W: io::Write + IsTerminal>(
+        &self,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut W,
+    ) -> Result<(), TableError> {
+        let is_terminal = writer.is_terminal();
+        write_io(writer, |w| {
+            self.render_to(Renderer::Normal, rows, w, is_terminal)
+        })
+    }
+
+    /// This works like [rendered_by], but writes to a [fmt::Write].
+    ///
+    /// [rendered_by]: #method.rendered_by
+    pub fn render_fmt(
+        &self,
+        setting: Renderer,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut dyn fmt::Write,
+    ) -> Result<(), TableError> {
+        self.render_to(setting, rows, writer, false)
+    }
+
+    /// Render the rows to an output which is a terminal or not.
+    fn render_to(
+        &self,
+        setting: Renderer,
+        rows: impl IntoIterator<Item = Vec<Cell>>,
+        writer: &mut dyn fmt::Write,
+        is_terminal: bool,
+    ) -> Result<(), TableError> {
+        let mut rows = rows.into_iter();
+        // Without header, the first row is always measured, so that the columns have a width.
+        let sample: Vec<_> = match self.widths {
+            StreamWidths::Sampled(n) => rows
+                .by_ref()
+                .take(n.max(self.header.is_empty() as usize))
+                .collect(),
+            StreamWidths::Fixed(_) => vec![],
+        };
+        let columns = match (&self.widths, self.header.first(), sample.first()) {
+            (StreamWidths::Fixed(w), _, _) => w.len(),
+            (_, Some(h), _) | (_, _, Some(h)) => h.len(),
+            _ => 0,
+        };
+        for (y, row) in self.header.iter().chain(sample.iter()).enumerate() {
+            check(row, y, columns)?;
+        }
+
+        let ansi = match setting {
+            Renderer::Normal => self
+                .color_policy
+                .should_colorize(is_terminal)
+                .then_some(self.color_depth),
+            Renderer::Raw => None,
+            Renderer::Markdown => {
+                let mut rows = self.header.iter().cloned().chain(sample).chain(rows);
+                if let Some(h) = rows.next() {
+                    write_markdown_header(writer, &h, &|_, _| Decoration::NONE)?;
+                    for (y, row) in rows.enumerate() {
+                        check(&row, y + 1, columns)?;
+                        write_markdown_row(writer, &row, &|_, _| Decoration::NONE)?;
+                    }
+                } else {
+                    writeln!(writer, "┃┃\n┃-┃")?;
+                }
+                return Ok(());
+            }
+        };
+
+        let (widths, fractions) = match self.widths {
+            StreamWidths::Fixed(ref w) => (w.clone(), vec![0; w.len()]),
+            StreamWidths::Sampled(_) => measure(
+                self.header
+                    .iter()
+                    .chain(sample.iter())
+                    .map(|r| r.as_slice()),
+                columns,
+            )?,
+        };
+        let rule = Line::Rule {
+            width: table_width(&widths, self.border),
+        };
+        if self.border.top {
+            rule.write(writer, ansi)?;
+        }
+        for (y, row) in self.header.iter().enumerate() {
+            self.write_row(writer, row, y, (&widths, &fractions), ansi)?;
+        }
+        let offset = self.header.len();
+        for (y, row) in sample.into_iter().chain(rows).enumerate() {
+            check(&row, y + offset, columns)?;
+            self.write_row(writer, &row, y + offset, (&widths, &fractions), ansi)?;
+        }
+        if self.border.bottom {
+            rule.write(writer, ansi)?;
+        }
+        Ok(())
+    }
+
+    fn write_row(
+        &self,
+        writer: &mut dyn fmt::Write,
+        row: &[Cell],
+        y: usize,
+        (widths, fractions): (&[usize], &[usize]),
+        ansi: Option<ColorDepth>,
+    ) -> Result<(), TableError> {
+        Line::Row {
+            left: self.border.left,
+            right: self.border.right,
+            cells: place_row(row, y, widths, fractions)?,
+        }
+        .write(writer, ansi)?;
+        Ok(())
+    }
+}
+
+fn check(row: &[Cell], y: usize, columns: usize) -> Result<(), TableError> {
+    if row.len() == columns {
+        Ok(())
+    } else {
+        Err(TableError::InvalidTableSize {
+            row: y,
+            expected: columns,
+            actual: row.len(),
+        })
+    }
+}
+
+#[test]
+fn test_stream_matches_table() {
+    use crate::cell;
+    use crate::table::{Overflow, Table};
+
+    let header = || vec![cell!("id"), cell!("name")];
+    let rows = || {
+        (0..4).map(|i| {
+            vec![
+                cell!(i),
+                cell!("x".repeat(i * 3)).with_overflow(Overflow::Hidden),
+            ]
+        })
+    };
+    let table = Table::create(header(), rows().collect(), true).with_border(Border::ALL);
+
+    let stream = TableStream::new(StreamWidths::Sampled(usize::MAX))
+        .with_header(header(), true)
+        .with_border(Border::ALL);
+    for setting in [Renderer::Normal, Renderer::Raw, Renderer::Markdown] {
+        let mut s = String::new();
+        stream.render_fmt(setting, rows(), &mut s).unwrap();
+        assert_eq!(s, table.to_string_with(setting).unwrap());
+    }
+
+    let mut s = String::new();
+    stream
+        .clone()
+        .with_widths(StreamWidths::Fixed(vec![2, 4]))
+        .render_fmt(Renderer::Raw, rows(), &mut s)
+        .unwrap();
+    assert_eq!(
+        s,
+        "━━━━━━━━━━━━\n┃ id  name ┃\n┃━━━━━━━━━━┃\n┃ 0        ┃\n┃ 1   xxx  ┃\n┃ 2   xxxx ┃\n┃ 3   xxxx ┃\n━━━━━━━━━━━━\n"
+    );
+
+    let unsampled = TableStream::new(StreamWidths::Sampled(0));
+    let mut s = String::new();
+    let words = vec![vec![cell!("hello world")], vec![cell!("hi")]];
+    unsampled.render_fmt(Renderer::Raw, words, &mut s).unwrap();
+    assert_eq!(s, " hello world \n hi          \n");
+
+    let stream = TableStream::new(StreamWidths::Fixed(vec![2, 4]));
+    let bad = vec![vec![cell!(1), cell!(2)], vec![cell!(3)]];
+    assert!(matches!(
+        unsampled.render_fmt(Renderer::Raw, bad.clone(), &mut String::new()),
+        Err(TableError::InvalidTableSize {
+            row: 1,
+            expected: 2,
+            actual: 1
+        })
+    ));
+    assert!(matches!(
+        stream.render_fmt(Renderer::Raw, bad, &mut String::new()),
+        Err(TableError::InvalidTableSize {
+            row: 1,
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
@@ -0,0 +1,503 @@
+use colored::{ColoredString, Colorize, Styles};
+
+use crate::table::ColorDepth;
+
+/// Colors usable in a [Style].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// An entry of the xterm 256-color palette.
+    Fixed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// RGB value of the color. Named colors use the default xterm palette.
+    pub(crate) fn rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::BrightBlack => (127, 127, 127),
+            Color::BrightRed => (255, 0, 0),
+            Color::BrightGreen => (0, 255, 0),
+            Color::BrightYellow => (255, 255, 0),
+            Color::BrightBlue => (92, 92, 255),
+            Color::BrightMagenta => (255, 0, 255),
+            Color::BrightCyan => (0, 255, 255),
+            Color::BrightWhite => (255, 255, 255),
+            Color::Fixed(n) => fixed_rgb(n),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// The nearest color available at `depth`, or `None` if colors are disabled.
+    pub(crate) fn downgrade(self, depth: ColorDepth) -> Option<Color> {
+        match (depth, self) {
+            (ColorDepth::None, _) => None,
+            (ColorDepth::TrueColor, c) => Some(c),
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => {
+                Some(Color::Fixed(nearest_fixed(r, g, b)))
+            }
+            (ColorDepth::Ansi256, c) => Some(c),
+            (ColorDepth::Ansi16, c @ (Color::Fixed(_) | Color::Rgb(..))) => {
+                let rgb = c.rgb();
+                NAMED.iter().copied().min_by_key(|n| distance(n.rgb(), rgb))
+            }
+            (ColorDepth::Ansi16, c) => Some(c),
+        }
+    }
+
+    /// SGR parameters selecting this color.
+    fn ansi(&self, background: bool) -> String {
+        let offset = if background { 10 } else { 0 };
+        let named = |base: u8| (base + offset).to_string();
+        match *self {
+            Color::Black => named(30),
+            Color::Red => named(31),
+            Color::Green => named(32),
+            Color::Yellow => named(33),
+            Color::Blue => named(34),
+            Color::Magenta => named(35),
+            Color::Cyan => named(36),
+            Color::White => named(37),
+            Color::BrightBlack => named(90),
+            Color::BrightRed => named(91),
+            Color::BrightGreen => named(92),
+            Color::BrightYellow => named(93),
+            Color::BrightBlue => named(94),
+            Color::BrightMagenta => named(95),
+            Color::BrightCyan => named(96),
+            Color::BrightWhite => named(97),
+            Color::Fixed(n) => format!("{};5;{}", 38 + offset, n),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", 38 + offset, r, g, b),
+        }
+    }
+}
+
+const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// Channel levels of the 6x6x6 color cube of the 256-color palette.
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn fixed_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => NAMED[n as usize].rgb(),
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE[(n / 36) as usize],
+                CUBE[(n / 6 % 6) as usize],
+                CUBE[(n % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (n - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// The entry of the 256-color palette closest to an RGB color, ignoring the 16 configurable ones.
+fn nearest_fixed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE.len())
+            .min_by_key(|&i| (CUBE[i] as i32 - c as i32).abs())
+            .unwrap() as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + ((average.saturating_sub(3)) / 10).min(23) as u8;
+    [cube, gray]
+        .into_iter()
+        .min_by_key(|&n| distance(fixed_rgb(n), (r, g, b)))
+        .unwrap()
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+impl From<Color> for colored::Color {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::White,
+            Color::BrightBlack => Self::BrightBlack,
+            Color::BrightRed => Self::BrightRed,
+            Color::BrightGreen => Self::BrightGreen,
+            Color::BrightYellow => Self::BrightYellow,
+            Color::BrightBlue => Self::BrightBlue,
+            Color::BrightMagenta => Self::BrightMagenta,
+            Color::BrightCyan => Self::BrightCyan,
+            Color::BrightWhite => Self::BrightWhite,
+            Color::Fixed(_) => {
+                let (r, g, b) = value.rgb();
+                Self::TrueColor { r, g, b }
+            }
+            Color::Rgb(r, g, b) => Self::TrueColor { r, g, b },
+        }
+    }
+}
+
+impl From<colored::Color> for Color {
+    fn from(value: colored::Color) -> Self {
+        match value {
+            colored::Color::Black => Self::Black,
+            colored::Color::Red => Self::Red,
+            colored::Color::Green => Self::Green,
+            colored::Color::Yellow => Self::Yellow,
+            colored::Color::Blue => Self::Blue,
+            colored::Color::Magenta => Self::Magenta,
+            colored::Color::Cyan => Self::Cyan,
+            colored::Color::White => Self::White,
+            colored::Color::BrightBlack => Self::BrightBlack,
+            colored::Color::BrightRed => Self::BrightRed,
+            colored::Color::BrightGreen => Self::BrightGreen,
+            colored::Color::BrightYellow => Self::BrightYellow,
+            colored::Color::BrightBlue => Self::BrightBlue,
+            colored::Color::BrightMagenta => Self::BrightMagenta,
+            colored::Color::BrightCyan => Self::BrightCyan,
+            colored::Color::BrightWhite => Self::BrightWhite,
+            colored::Color::TrueColor { r, g, b } => Self::Rgb(r, g, b),
+        }
+    }
+}
+
+/// Declarative style of a cell.
+///
+/// Unlike [FormatterFunc], a style is plain data: it can be compared, serialized (with the `serde` feature),
+/// and translated by backends other than the ANSI one.
+///
+/// Styles are applied before the formatters of a cell, which remain available as an escape hatch.
+///
+/// ```rust
+/// # use table_formatter::table::{Color, Style};
+/// let style = Style::new().foreground(Color::Red).bold();
+/// assert_eq!(style.merge(Style::new().italic()), Style::new().foreground(Color::Red).bold().italic());
+/// ```
+///
+/// [FormatterFunc]: enum.FormatterFunc.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub dim: bool,
+    pub strikethrough: bool,
+}
+
+impl Style {
+    pub const NONE: Style = Style::new();
+
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            bold: false,
+            italic: false,
+            underline: false,
+            dim: false,
+            strikethrough: false,
+        }
+    }
+
+    pub const fn foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+    pub const fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Layer `other` on top of this style: colors set in `other` win, attributes are combined.
+    pub fn merge(self, other: Style) -> Style {
+        Style {
+            foreground: other.foreground.or(self.foreground),
+            background: other.background.or(self.background),
+            bold: self.bold || other.bold,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+            dim: self.dim || other.dim,
+            strikethrough: self.strikethrough || other.strikethrough,
+        }
+    }
+
+    /// Check if the style changes nothing.
+    pub fn is_plain(&self) -> bool {
+        *self == Style::NONE
+    }
+
+    /// Read the style carried by a [ColoredString].
+    pub fn of(s: &ColoredString) -> Style {
+        let style = s.style;
+        Style {
+            foreground: s.fgcolor.map(Color::from),
+            background: s.bgcolor.map(Color::from),
+            bold: style.contains(Styles::Bold),
+            italic: style.contains(Styles::Italic),
+            underline: style.contains(Styles::Underline),
+            dim: style.contains(Styles::Dimmed),
+            strikethrough: style.contains(Styles::Strikethrough),
+        }
+    }
+
+    pub(crate) fn apply(&self, mut s: ColoredString) -> ColoredString {
+        if let Some(c) = self.foreground {
+            s = s.color(c);
+        }
+        if let Some(c) = self.background {
+            s = s.on_color(c);
+        }
+        if self.bold {
+            s = s.bold();
+        }
+        if self.italic {
+            s = s.italic();
+        }
+        if self.underline {
+            s = s.underline();
+        }
+        if self.dim {
+            s = s.dimmed();
+        }
+        if self.strikethrough {
+            s = s.strikethrough();
+        }
+        s
+    }
+
+    /// Wrap `text` with the ANSI escape sequences of the style, using the colors available at `depth`.
+    pub(crate) fn paint(&self, text: &str, depth: ColorDepth) -> String {
+        self.paint_with(text, depth, vec![])
+    }
+
+    /// Paint the output of formatters given a string styled with `self`, keeping the attributes which [Style] can't represent,
+    /// such as `reversed`, `blink` and `hidden`.
+    ///
+    /// [ColoredString] has no palette colors, so the colors of `self` are kept unless the formatters changed them.
+    pub(crate) fn paint_colored(&self, s: &ColoredString, depth: ColorDepth) -> String {
+        let extra = [
+            (Styles::Blink, "5"),
+            (Styles::Reversed, "7"),
+            (Styles::Hidden, "8"),
+        ]
+        .into_iter()
+        .filter(|(attribute, _)| s.style.contains(*attribute))
+        .map(|(_, code)| code.to_string())
+        .collect();
+        let mut style = Style::of(s);
+        let read_back = |c: Option<Color>| c.map(|c| Color::from(colored::Color::from(c)));
+        if style.foreground == read_back(self.foreground) {
+            style.foreground = self.foreground;
+        }
+        if style.background == read_back(self.background) {
+            style.background = self.background;
+        }
+        style.paint_with(s, depth, extra)
+    }
+
+    /// Paint `text`, adding the SGR `extra` codes after the attributes of the style.
+    fn paint_with(&self, text: &str, depth: ColorDepth, extra: Vec<String>) -> String {
+        let style = Style {
+            foreground: self.foreground.and_then(|c| c.downgrade(depth)),
+            background: self.background.and_then(|c| c.downgrade(depth)),
+            ..*self
+        };
+        if (style.is_plain() && extra.is_empty()) || text.is_empty() {
+            return text.to_string();
+        }
+        let mut codes = vec![];
+        for (set, code) in [
+            (style.bold, "1"),
+            (style.dim, "2"),
+            (style.italic, "3"),
+            (style.underline, "4"),
+            (style.strikethrough, "9"),
+        ] {
+            if set {
+                codes.push(code.to_string());
+            }
+        }
+        codes.extend(extra);
+        if let Some(c) = style.foreground {
+            codes.push(c.ansi(false));
+        }
+        if let Some(c) = style.background {
+            codes.push(c.ansi(true));
+        }
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+
+    /// Wrap `text` with the markdown equivalents of the style.
+    ///
+    /// Colors, underline and dim have no markdown equivalent and are dropped.
+    pub(crate) fn markdown(&self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+        let mut s = text.to_string();
+        if self.strikethrough {
+            s = format!("~~{}~~", s);
+        }
+        if self.italic {
+            s = format!("*{}*", s);
+        }
+        if self.bold {
+            s = format!("**{}**", s);
+        }
+        s
+    }
+}
+
+/// Table-level styles applied around the cell's own style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct Decoration {
+    /// Layered under the cell's own style, such as row stripes.
+    pub(crate) base: Style,
+    /// Layered on top of the cell's own style, such as conditional formatting.
+    pub(crate) overlay: Style,
+}
+
+impl Decoration {
+    pub(crate) const NONE: Decoration = Decoration {
+        base: Style::NONE,
+        overlay: Style::NONE,
+    };
+
+    /// The effective style of a cell whose own style is `style`.
+    pub(crate) fn around(&self, style: Style) -> Style {
+        self.base.merge(style).merge(self.overlay)
+    }
+}
+
+#[test]
+fn test_style() {
+    let style = Style::new()
+        .foreground(Color::Rgb(1, 2, 3))
+        .background(Color::Blue)
+        .bold()
+        .underline();
+    assert_eq!(Style::of(&style.apply("x".into())), style);
+    assert_eq!(
+        Style::new()
+            .foreground(Color::Red)
+            .merge(Style::new().foreground(Color::Green).dim()),
+        Style::new().foreground(Color::Green).dim()
+    );
+    assert!(Style::NONE.is_plain());
+    assert_eq!(Style::new().bold().italic().markdown("x"), "***x***");
+    assert_eq!(Style::NONE.paint("x", ColorDepth::TrueColor), "x");
+    assert_eq!(
+        Style::new()
+            .bold()
+            .foreground(Color::BrightRed)
+            .background(Color::Rgb(1, 2, 3))
+            .paint("x", ColorDepth::TrueColor),
+        "\x1b[1;91;48;2;1;2;3mx\x1b[0m"
+    );
+    assert_eq!(Style::new().strikethrough().markdown("x"), "~~x~~");
+}
+
+#[test]
+fn test_color_depth() {
+    let orange = Color::Rgb(255, 135, 0);
+    assert_eq!(orange.downgrade(ColorDepth::TrueColor), Some(orange));
+    assert_eq!(
+        orange.downgrade(ColorDepth::Ansi256),
+        Some(Color::Fixed(208))
+    );
+    assert_eq!(
+        Color::Rgb(100, 100, 100).downgrade(ColorDepth::Ansi256),
+        Some(Color::Fixed(241))
+    );
+    assert_eq!(orange.downgrade(ColorDepth::Ansi16), Some(Color::Yellow));
+    assert_eq!(
+        Color::Fixed(196).downgrade(ColorDepth::Ansi16),
+        Some(Color::BrightRed)
+    );
+    assert_eq!(Color::Red.downgrade(ColorDepth::Ansi16), Some(Color::Red));
+    assert_eq!(orange.downgrade(ColorDepth::None), None);
+
+    let style = Style::new().bold().foreground(orange);
+    assert_eq!(
+        style.paint("x", ColorDepth::Ansi256),
+        "\x1b[1;38;5;208mx\x1b[0m"
+    );
+    assert_eq!(style.paint("x", ColorDepth::Ansi16), "\x1b[1;33mx\x1b[0m");
+    assert_eq!(style.paint("x", ColorDepth::None), "\x1b[1mx\x1b[0m");
+    assert_eq!(
+        Style::new().foreground(orange).paint("x", ColorDepth::None),
+        "x"
+    );
+}
@@ -1,10 +1,19 @@
-use std::io;
+use std::fmt;
+use std::io::{self, IsTerminal};
 
 use colored::Colorize;
 use itertools::Itertools;
 
 use crate::error::TableError;
-use crate::table::{Align, Border, Cell, Content, Overflow, Renderer};
+use crate::table::{
+    Align, Border, Cell, ColorDepth, ColorPolicy, ColorScale, Content, Footer, Overflow, Renderer,
+    Rule, Style,
+};
+
+use super::layout::{Layout, Line};
+use super::scale::PreparedScale;
+use super::style::Decoration;
+use super::writer::write_io;
 
 use super::FormatterFunc;
 
@@ -13,8 +22,19 @@ use super::FormatterFunc;
 /// For more information, please see the lib's documentation.
 #[derive(Clone)]
 pub struct Table {
-    table: Vec<Vec<Cell>>,
+    pub(super) table: Vec<Vec<Cell>>,
+    /// Number of leading rows that belong to the header, splitter included.
+    pub(super) header: usize,
     border: Border,
+    color_policy: ColorPolicy,
+    color_depth: ColorDepth,
+    rules: Vec<Rule>,
+    stripes: Vec<Style>,
+    scales: Vec<ColorScale>,
+    pub(super) caption: Option<String>,
+    pub(super) footers: Vec<Footer>,
+    /// Smallest width of every column, such as the widths of the whole table for a page.
+    pub(super) min_widths: Vec<usize>,
 }
 
 impl Table {
@@ -24,28 +44,12 @@ impl Table {
     ///
     /// > This is the recommended way to create a new table, so for details see the lib's documentation.
     pub fn create(header: Vec<Cell>, mut cell: Vec<Vec<Cell>>, splitter: bool) -> Table {
-        let mut v = if splitter {
-            let dat = header
-                .iter()
-                .map(|_| {
-                    Cell::default()
-                        .with_content(Content::Splitter)
-                        .with_formatter(vec![FormatterFunc::Normal(Colorize::bold)])
-                })
-                .collect_vec();
-            let mut v = vec![header
-                .into_iter()
-                .map(|c| c.with_formatter(vec![FormatterFunc::Normal(Colorize::bold)]))
-                .collect_vec()];
-            v.push(dat);
-            v
-        } else {
-            vec![header]
-        };
+        let mut v = header_rows(header, splitter);
+        let header = v.len();
         v.append(&mut cell);
         Self {
-            table: v,
-            border: Border::NONE,
+            header,
+            ..Self::new(v)
         }
     }
 
@@ -53,7 +57,16 @@ impl Table {
     pub fn new(table: Vec<Vec<Cell>>) -> Table {
         Self {
             table,
+            header: 0,
             border: Border::NONE,
+            color_policy: ColorPolicy::Auto,
+            color_depth: ColorDepth::TrueColor,
+            rules: vec![],
+            stripes: vec![],
+            scales: vec![],
+            caption: None,
+            footers: vec![],
+            min_widths: vec![],
         }
     }
 
@@ -64,6 +77,81 @@ impl Table {
     pub fn set_border(&mut self, border: Border) {
         self.border = border;
     }
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Table {
+        self.color_policy = policy;
+        self
+    }
+    pub fn set_color_policy(&mut self, policy: ColorPolicy) {
+        self.color_policy = policy;
+    }
+    /// Set the colors available on the output. Colors are mapped to the nearest supported one.
+    ///
+    /// See [ColorDepth].
+    ///
+    /// [ColorDepth]: ../enum.ColorDepth.html
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Table {
+        self.color_depth = depth;
+        self
+    }
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+    }
+
+    /// Add a conditional formatting rule, evaluated at render time. See [Rule].
+    ///
+    /// [Rule]: ../struct.Rule.html
+    pub fn with_rule(mut self, rule: Rule) -> Table {
+        self.rules.push(rule);
+        self
+    }
+    pub fn add_rule(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    /// Apply alternating styles to the data rows, cycling through `styles`.
+    ///
+    /// The header and splitter rows created by [create] are skipped. Stripes are layered under the cells' own styles and formatters.
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Color, Style, Table};
+    /// # use table_formatter::cell;
+    /// let table = Table::create(vec![cell!("name")], vec![vec![cell!("a")], vec![cell!("b")]], true)
+    ///     .with_stripes(vec![Style::NONE, Style::new().background(Color::BrightBlack)]);
+    /// ```
+    ///
+    /// [create]: #method.create
+    pub fn with_stripes(mut self, styles: Vec<Style>) -> Table {
+        self.stripes = styles;
+        self
+    }
+    pub fn set_stripes(&mut self, styles: Vec<Style>) {
+        self.stripes = styles;
+    }
+
+    /// Add a heatmap on a numeric column. See [ColorScale].
+    ///
+    /// [ColorScale]: ../struct.ColorScale.html
+    pub fn with_color_scale(mut self, scale: ColorScale) -> Table {
+        self.scales.push(scale);
+        self
+    }
+    pub fn add_color_scale(&mut self, scale: ColorScale) {
+        self.scales.push(scale);
+    }
+
+    /// Add a line of text under the table, such as a note or the position of a page.
+    ///
+    /// In markdown, the caption is separated from the table by an empty line.
+    pub fn with_caption(mut self, caption: impl ToString) -> Table {
+        self.caption = Some(caption.to_string());
+        self
+    }
+    pub fn set_caption(&mut self, caption: Option<String>) {
+        self.caption = caption;
+    }
+    pub fn get_caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
 
     /// This function will overwrite the `overflow` property of every cells in the table.
     pub fn overwrite_overflow(&mut self, overflow: Overflow) {
@@ -83,12 +171,55 @@ impl Table {
         &self,
         setting: Renderer,
         writer: &mut impl io::Write,
+    ) -> Result<(), TableError> {
+        write_io(writer, |w| self.render_fmt(setting, w))
+    }
+
+    /// This works like [rendered_by], but writes to a [fmt::Write] such as a `String` or a `fmt::Formatter`.
+    ///
+    /// As the output can't be inspected, [ColorPolicy::Auto] treats it as not being a terminal, like every writer
+    /// but the one given to [render_auto].
+    ///
+    /// ```rust
+    /// # use table_formatter::table::{Renderer, Table};
+    /// # use table_formatter::cell;
+    /// let table = Table::new(vec![vec![cell!("hello"), cell!("world")]]);
+    /// let mut s = String::new();
+    /// table.render_fmt(Renderer::Raw, &mut s).unwrap();
+    /// assert_eq!(s, " hello  world \n");
+    /// ```
+    ///
+    /// [rendered_by]: #method.rendered_by
+    /// [render_auto]: #method.render_auto
+    pub fn render_fmt(
+        &self,
+        setting: Renderer,
+        writer: &mut dyn fmt::Write,
     ) -> Result<(), TableError> {
         match setting {
-            Renderer::Normal => self.render(writer),
-            Renderer::Raw => self.render_raw(writer),
-            Renderer::Markdown => self.render_markdown(writer),
+            Renderer::Normal => {
+                let ansi = self.ansi(false);
+                let footer = self.footer_rows(self.validate()?)?;
+                self.layout(&footer)?.write(writer, ansi)?
+            }
+            Renderer::Raw => {
+                let footer = self.footer_rows(self.validate()?)?;
+                self.layout(&footer)?.write(writer, None)?
+            }
+            Renderer::Markdown => self.write_markdown(writer)?,
         }
+        Ok(())
+    }
+
+    /// Render the table to a new `String`.
+    ///
+    /// The [Display] implementation is equivalent to `to_string_with(Renderer::Normal)`, but writes invalid tables as their error.
+    ///
+    /// [Display]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    pub fn to_string_with(&self, setting: Renderer) -> Result<String, TableError> {
+        let mut s = String::new();
+        self.render_fmt(setting, &mut s)?;
+        Ok(s)
     }
 
     /// This will render a markdown-formatted table.
@@ -97,42 +228,7 @@ impl Table {
     ///
     /// [rendered_by]: #method.rendered_by
     pub fn render_markdown(&self, writer: &mut impl io::Write) -> Result<(), TableError> {
-        self.validate()?;
-        let mut rows = self.table.iter();
-        if let Some(h) = rows.next() {
-            let setting_row = h
-                .iter()
-                .map(|c| match c.get_align() {
-                    Align::Left => ":--",
-                    Align::Center => ":-:",
-                    Align::Right => "--:",
-                })
-                .join("┃");
-            let hr = h
-                .iter()
-                .map(|c| match c.get_content() {
-                    Content::None => "".to_string(),
-                    Content::Splitter => "━━━".to_string(),
-                    Content::Text(ref text) => text.clone(),
-                })
-                .join("┃");
-            writeln!(writer, "┃{}┃", hr)?;
-            writeln!(writer, "┃{}┃", setting_row)?;
-            for row in rows {
-                let hr = row
-                    .iter()
-                    .map(|c| match c.get_content() {
-                        Content::None => "".to_string(),
-                        Content::Splitter => "━━━".to_string(),
-                        Content::Text(ref text) => text.clone(),
-                    })
-                    .join("┃");
-                writeln!(writer, "┃{}┃", hr)?;
-            }
-        } else {
-            writeln!(writer, "┃┃\n┃-┃")?;
-        }
-        Ok(())
+        self.rendered_by(Renderer::Markdown, writer)
     }
 
     /// This will render a raw table without any formatting.
@@ -141,162 +237,115 @@ impl Table {
     ///
     /// [rendered_by]: #method.rendered_by
     pub fn render_raw(&self, writer: &mut impl io::Write) -> Result<(), TableError> {
-        let w = self.validate()?;
-        let widths = self.update_width(w)?;
-        let table_width = widths.iter().map(|v| v + 2).sum::<usize>()
-            + if self.border.left { 1 } else { 0 }
-            + if self.border.right { 1 } else { 0 };
-        if self.border.top {
-            writeln!(writer, "{}", "━".repeat(table_width))?;
-        }
-        for (y, row) in self.table.iter().enumerate() {
-            if self.border.left {
-                write!(writer, "┃")?;
-            }
-            let mut pass: Vec<usize> = vec![];
-            'cell: for (x, cell) in row.iter().enumerate() {
-                if pass.binary_search(&x).is_ok() {
-                    continue 'cell;
-                }
-                if let Some(cell_width) = widths.get(x) {
-                    let render_width = if let Some(merge_width) = cell.get_merge() {
-                        let cells = row.get((x + 1)..=(x + merge_width));
-                        if let Some(merged) = cells {
-                            let mut mv_width = vec![];
-                            for (mx, cm) in merged.iter().enumerate() {
-                                if !matches!(cm.get_content(), Content::None) {
-                                    Err(TableError::MergeOverwrite { x: x + mx + 1, y })?
-                                }
-                                if let Some(mw) = widths.get(mx + x + 1) {
-                                    mv_width.push(mw + 2);
-                                } else {
-                                    Err(TableError::Unexpected(
-                                        "Render merge failed(index error)".to_string(),
-                                    ))?
-                                }
-                                pass.push(x + mx + 1);
-                            }
-                            mv_width.into_iter().sum::<usize>() + cell_width
-                        } else {
-                            Err(TableError::MergeOutOfTable {
-                                x,
-                                y,
-                                merge: merge_width,
-                                max: w - x - 1,
-                            })?
-                        }
-                    } else {
-                        *cell_width
-                    };
-                    let rendered = cell.render_with_width_raw(render_width);
-                    write!(writer, "{}", rendered)?;
-                } else {
-                    Err(TableError::Unexpected(
-                        "Getting width failed(index error)".to_string(),
-                    ))?
-                }
-            }
-            if self.border.right {
-                write!(writer, "┃")?;
-            }
-            writeln!(writer)?;
-        }
-        if self.border.bottom {
-            writeln!(writer, "{}", "━".repeat(table_width))?;
-        }
-        Ok(())
+        self.rendered_by(Renderer::Raw, writer)
     }
 
     /// This will render a table with formatting you defined.
     ///
+    /// With [ColorPolicy::Auto], styles are only emitted when forced, as `writer` may not be a terminal. See [render_auto].
+    ///
     /// See also [rendered_by].
     ///
     /// [rendered_by]: #method.rendered_by
+    /// [render_auto]: #method.render_auto
     pub fn render(&self, writer: &mut impl io::Write) -> Result<(), TableError> {
-        let w = self.validate()?;
-        let widths = self.update_width(w)?;
-        let table_width = widths.iter().map(|v| v + 2).sum::<usize>()
-            + if self.border.left { 1 } else { 0 }
-            + if self.border.right { 1 } else { 0 };
-        if self.border.top {
-            writeln!(writer, "{}", "━".repeat(table_width).bold())?;
-        }
-        for (y, row) in self.table.iter().enumerate() {
-            if self.border.left {
-                write!(writer, "{}", "┃".bold())?;
-            }
-            let mut pass: Vec<usize> = vec![];
-            'cell: for (x, cell) in row.iter().enumerate() {
-                if pass.binary_search(&x).is_ok() {
-                    continue 'cell;
-                }
-                if let Some(cell_width) = widths.get(x) {
-                    let render_width = if let Some(merge_width) = cell.get_merge() {
-                        let cells = row.get((x + 1)..=(x + merge_width));
-                        if let Some(merged) = cells {
-                            let mut mv_width = vec![];
-                            for (mx, cm) in merged.iter().enumerate() {
-                                if !matches!(cm.get_content(), Content::None) {
-                                    Err(TableError::MergeOverwrite { x: x + mx + 1, y })?
-                                }
-                                if let Some(mw) = widths.get(mx + x + 1) {
-                                    mv_width.push(mw + 2);
-                                } else {
-                                    Err(TableError::Unexpected(
-                                        "Render merge failed(index error)".to_string(),
-                                    ))?
-                                }
-                                pass.push(x + mx + 1);
-                            }
-                            mv_width.into_iter().sum::<usize>() + cell_width
-                        } else {
-                            Err(TableError::MergeOutOfTable {
-                                x,
-                                y,
-                                merge: merge_width,
-                                max: w - x - 1,
-                            })?
-                        }
-                    } else {
-                        *cell_width
-                    };
-                    let rendered = cell.render_with_width(render_width);
-                    write!(writer, "{}", rendered)?;
-                } else {
-                    Err(TableError::Unexpected(
-                        "Getting width failed(index error)".to_string(),
-                    ))?
-                }
-            }
-            if self.border.right {
-                write!(writer, "{}", "┃".bold())?;
+        self.rendered_by(Renderer::Normal, writer)
+    }
+
+    /// This works like [render], but [ColorPolicy::Auto] checks whether `writer` itself is a terminal.
+    ///
+    /// ```rust,no_run
+    /// # use table_formatter::table::Table;
+    /// # use table_formatter::cell;
+    /// let table = Table::new(vec![vec![cell!("hello")]]);
+    /// table.render_auto(&mut std::io::stderr()).unwrap();
+    /// ```
+    ///
+    /// [render]: #method.render
+    pub fn render_auto<W: io::Write + IsTerminal>(&self, writer: &mut W) -> Result<(), TableError> {
+        let ansi = self.ansi(writer.is_terminal());
+        let footer = self.footer_rows(self.validate()?)?;
+        let layout = self.layout(&footer)?;
+        write_io(writer, |w| Ok(layout.write(w, ansi)?))
+    }
+
+    /// The color depth of the normal renderer, or `None` if the color policy disables styles.
+    fn ansi(&self, is_terminal: bool) -> Option<ColorDepth> {
+        self.color_policy
+            .should_colorize(is_terminal)
+            .then_some(self.color_depth)
+    }
+
+    fn write_markdown(&self, writer: &mut dyn fmt::Write) -> Result<(), TableError> {
+        let footer = self.footer_rows(self.validate()?)?;
+        let mut rows = self.table.iter().chain(footer.iter());
+        if let Some(h) = rows.next() {
+            write_markdown_header(writer, h, &|x, c| self.decoration(x, 0, c, &[]))?;
+            for (y, row) in rows.enumerate() {
+                write_markdown_row(writer, row, &|x, c| self.decoration(x, y + 1, c, &[]))?;
             }
-            writeln!(writer)?;
+        } else {
+            writeln!(writer, "┃┃\n┃-┃")?;
         }
-        if self.border.bottom {
-            writeln!(writer, "{}", "━".repeat(table_width).bold())?;
+        if let Some(caption) = &self.caption {
+            writeln!(writer, "\n{}", caption)?;
         }
         Ok(())
     }
 
-    fn update_width(&self, w: usize) -> Result<Vec<usize>, TableError> {
-        let mut v = std::iter::repeat(0).take(w).collect_vec();
-        for row in self.table.iter() {
-            for (index, cell) in row.iter().enumerate() {
-                if let Some(c) = v.get_mut(index) {
-                    if let Some(iw) = cell.get_width() {
-                        if iw > *c {
-                            *c = iw;
-                        }
-                    }
-                } else {
-                    Err(TableError::Unexpected(
-                        "Update width failed(index error)".to_string(),
-                    ))?
+    /// Lay out the table followed by the `footer` rows.
+    fn layout<'a>(&'a self, footer: &'a [Vec<Cell>]) -> Result<Layout<'a>, TableError> {
+        let w = self.validate()?;
+        let scales = self
+            .scales
+            .iter()
+            .map(|scale| {
+                let values = self.table[self.header.min(self.table.len())..]
+                    .iter()
+                    .filter_map(|row| row.get(scale.get_column()))
+                    .filter_map(|c| c.get_content().get_number())
+                    .collect();
+                scale.prepare(values)
+            })
+            .collect::<Vec<_>>();
+        let rows = self
+            .table
+            .iter()
+            .chain(footer)
+            .map(|r| r.as_slice())
+            .collect::<Vec<_>>();
+        let mut layout = Layout::build(&rows, w, &self.min_widths, self.border, &|x, y, c| {
+            self.decoration(x, y, c, &scales)
+        })?;
+        if let Some(caption) = &self.caption {
+            layout.lines.push(Line::Caption(caption));
+        }
+        Ok(layout)
+    }
+
+    /// The table-level styles of the cell at `(x, y)`, from the stripes, the color scales and the conditional formatting rules.
+    fn decoration(&self, x: usize, y: usize, cell: &Cell, scales: &[PreparedScale]) -> Decoration {
+        if y < self.header || y >= self.table.len() {
+            return Decoration::NONE;
+        }
+        let mut base = if self.stripes.is_empty() {
+            Style::NONE
+        } else {
+            self.stripes[(y - self.header) % self.stripes.len()]
+        };
+        if let Some(value) = cell.get_content().get_number() {
+            for scale in scales.iter().filter(|s| s.column() == x) {
+                if let Some(color) = scale.color(value) {
+                    base.background = Some(color);
                 }
             }
         }
-        Ok(v)
+        let overlay = self
+            .rules
+            .iter()
+            .filter_map(|r| r.style_for(x, cell.get_content()))
+            .fold(Style::NONE, Style::merge);
+        Decoration { base, overlay }
     }
 
     /// Check if the table is valid. The `usize` represents how many columns the table has.
@@ -323,6 +372,103 @@ impl Table {
     }
 }
 
+/// Build the header rows used by [Table::create], with a bold splitter row if required.
+pub(super) fn header_rows(header: Vec<Cell>, splitter: bool) -> Vec<Vec<Cell>> {
+    if splitter {
+        let dat = splitter_row(header.len());
+        let mut v = vec![header
+            .into_iter()
+            .map(|c| c.with_formatter(vec![FormatterFunc::Normal(Colorize::bold)]))
+            .collect_vec()];
+        v.push(dat);
+        v
+    } else {
+        vec![header]
+    }
+}
+
+/// A row of `columns` bold splitter cells.
+pub(super) fn splitter_row(columns: usize) -> Vec<Cell> {
+    (0..columns)
+        .map(|_| {
+            Cell::default()
+                .with_content(Content::Splitter)
+                .with_formatter(vec![FormatterFunc::Normal(Colorize::bold)])
+        })
+        .collect_vec()
+}
+
+/// Write the first row of a markdown table, followed by the alignment row.
+///
+/// `styles` gives the table-level styles of the cell in column `x`.
+pub(super) fn write_markdown_header(
+    writer: &mut dyn fmt::Write,
+    row: &[Cell],
+    styles: &dyn Fn(usize, &Cell) -> Decoration,
+) -> fmt::Result {
+    let setting_row = row
+        .iter()
+        .map(|c| match c.get_align() {
+            Align::Left => ":--",
+            Align::Center => ":-:",
+            Align::Right | Align::Decimal(_) => "--:",
+        })
+        .join("┃");
+    write_markdown_row(writer, row, styles)?;
+    writeln!(writer, "┃{}┃", setting_row)
+}
+
+pub(super) fn write_markdown_row(
+    writer: &mut dyn fmt::Write,
+    row: &[Cell],
+    styles: &dyn Fn(usize, &Cell) -> Decoration,
+) -> fmt::Result {
+    let hr = row
+        .iter()
+        .enumerate()
+        .map(|(x, c)| {
+            let text = match c.get_content() {
+                Content::None => return "".to_string(),
+                Content::Splitter => return "━━━".to_string(),
+                Content::Spans(ref spans) => {
+                    spans.iter().map(|s| s.style.markdown(&s.text)).collect()
+                }
+                content => content.get_text().unwrap_or_default(),
+            };
+            let text = styles(x, c).around(c.get_style()).markdown(&text);
+            match c.get_link() {
+                Some(link) => markdown_link(&text, link),
+                None => text,
+            }
+        })
+        .join("┃");
+    writeln!(writer, "┃{}┃", hr)
+}
+
+/// A markdown link. Brackets in `text` are escaped, and `link` is wrapped in `<...>` when it has spaces or parentheses.
+fn markdown_link(text: &str, link: &str) -> String {
+    let text = text.replace('[', "\\[").replace(']', "\\]");
+    if link.contains([' ', '(', ')', '<', '>']) {
+        let link = link.replace('<', "\\<").replace('>', "\\>");
+        format!("[{}](<{}>)", text, link)
+    } else {
+        format!("[{}]({})", text, link)
+    }
+}
+
+/// Formats like `to_string_with(Renderer::Normal)`.
+///
+/// An invalid table is written as `<invalid table: ...>` with the error, as `Display` can't report it.
+/// Use [Table::to_string_with] to check for errors.
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_string_with(Renderer::Normal) {
+            Ok(s) => f.write_str(&s),
+            Err(e) => write!(f, "<invalid table: {}>", e),
+        }
+    }
+}
+
 #[test]
 fn test_merge_cell() {
     let table = Table::new(vec![
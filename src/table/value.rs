@@ -0,0 +1,380 @@
+/// A typed value in a cell, see [Content::Value].
+///
+/// Values are formatted by a [ValueFormat] when the table renders, while rules and color scales still see the raw numbers.
+///
+/// [Content::Value]: enum.Content.html#variant.Value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Float(f64),
+    /// An exact decimal number: `Decimal(12345, 2)` is `123.45`.
+    Decimal(i128, u32),
+    Bool(bool),
+    /// A missing value.
+    Null,
+    /// Seconds since the Unix epoch, shown in UTC.
+    Timestamp(i64),
+}
+
+impl Value {
+    /// The value as a number, if it is one.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Integer(i) => Some(i as f64),
+            Value::Float(f) => Some(f),
+            Value::Decimal(m, s) => Some(m as f64 / 10f64.powi(s as i32)),
+            _ => None,
+        }
+    }
+
+    pub fn is_number(&self) -> bool {
+        self.as_f64().is_some()
+    }
+}
+
+macro_rules! value_from {
+    ($variant: ident: $($t: ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+value_from!(Integer: i8, i16, i32, i64, u8, u16, u32);
+value_from!(Float: f32, f64);
+value_from!(Bool: bool);
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+/// Units a number can be shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unit {
+    #[default]
+    None,
+    /// Multiply by 100 and add `%`.
+    Percent,
+    /// Powers of 1000 with SI prefixes: `1.5k`, `2.0M`.
+    Si,
+    /// Powers of 1024 with binary prefixes: `512 B`, `1.5 KiB`.
+    Bytes,
+}
+
+/// How a [Value] is turned into text.
+///
+/// The separators can be set to follow a locale, for example `1.234,5` with `with_thousands(Some('.'))` and `with_decimal_point(',')`.
+///
+/// ```rust
+/// # use table_formatter::table::{Unit, Value, ValueFormat};
+/// let format = ValueFormat::default().with_precision(Some(2)).with_thousands(Some(','));
+/// assert_eq!(format.format(&Value::Float(1234.5)), "1,234.50");
+/// let format = ValueFormat::default().with_unit(Unit::Bytes);
+/// assert_eq!(format.format(&Value::Integer(1536)), "1.5 KiB");
+/// let format = ValueFormat::default().with_date_pattern("%d/%m/%Y");
+/// assert_eq!(format.format(&Value::Timestamp(86400)), "02/01/1970");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueFormat {
+    precision: Option<usize>,
+    thousands: Option<char>,
+    decimal_point: char,
+    unit: Unit,
+    date_pattern: String,
+    null: String,
+}
+
+impl Default for ValueFormat {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            thousands: None,
+            decimal_point: '.',
+            unit: Unit::None,
+            date_pattern: "%Y-%m-%d %H:%M:%S".to_string(),
+            null: String::new(),
+        }
+    }
+}
+
+impl ValueFormat {
+    /// Number of digits after the decimal point. By default, floats use the shortest exact representation,
+    /// integers and decimals use their own digits, and SI and byte units use one digit, none for unscaled integers.
+    pub fn with_precision(mut self, precision: Option<usize>) -> Self {
+        self.precision = precision;
+        self
+    }
+    pub fn with_thousands(mut self, separator: Option<char>) -> Self {
+        self.thousands = separator;
+        self
+    }
+    pub fn with_decimal_point(mut self, separator: char) -> Self {
+        self.decimal_point = separator;
+        self
+    }
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+    /// Pattern of timestamps. Supports `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%b` (short month name) and `%%`.
+    pub fn with_date_pattern(mut self, pattern: impl ToString) -> Self {
+        self.date_pattern = pattern.to_string();
+        self
+    }
+    /// Text of [Value::Null]. Empty by default.
+    pub fn with_null(mut self, null: impl ToString) -> Self {
+        self.null = null.to_string();
+        self
+    }
+
+    pub fn get_precision(&self) -> Option<usize> {
+        self.precision
+    }
+    pub fn get_unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Turn `value` into text.
+    pub fn format(&self, value: &Value) -> String {
+        match *value {
+            Value::Bool(b) => b.to_string(),
+            Value::Null => self.null.clone(),
+            Value::Timestamp(t) => format_date(t, &self.date_pattern),
+            Value::Integer(i) => {
+                self.format_number(i < 0, i.unsigned_abs().to_string(), String::new(), i as f64)
+            }
+            Value::Float(f) if !f.is_finite() => f.to_string(),
+            Value::Float(f) => {
+                let text = match self.precision {
+                    Some(p) if self.unit == Unit::None => format!("{:.*}", p, f.abs()),
+                    _ => f.abs().to_string(),
+                };
+                let (int, frac) = text.split_once('.').unwrap_or((&text, ""));
+                self.format_number(f < 0.0, int.to_string(), frac.to_string(), f)
+            }
+            Value::Decimal(m, s) => {
+                let digits = format!("{:0>width$}", m.unsigned_abs(), width = s as usize + 1);
+                let (int, frac) = digits.split_at(digits.len() - s as usize);
+                self.format_number(
+                    m < 0,
+                    int.to_string(),
+                    frac.to_string(),
+                    value.as_f64().unwrap_or_default(),
+                )
+            }
+        }
+    }
+
+    /// Format a number given as its exact digits, or as a float when it has to be scaled.
+    fn format_number(&self, negative: bool, int: String, frac: String, float: f64) -> String {
+        let (int, frac, suffix) = match self.unit {
+            Unit::None => {
+                let (int, frac) = match self.precision {
+                    Some(p) => round_digits(&int, &frac, p),
+                    None => (int, frac),
+                };
+                (int, frac, "")
+            }
+            Unit::Percent => {
+                let (int, frac) = split(float.abs() * 100.0, self.precision.unwrap_or(0));
+                (int, frac, "%")
+            }
+            Unit::Si | Unit::Bytes => {
+                let (base, prefixes): (f64, &[&str]) = if self.unit == Unit::Si {
+                    (1000.0, &["", "k", "M", "G", "T", "P", "E"])
+                } else {
+                    (
+                        1024.0,
+                        &[" B", " KiB", " MiB", " GiB", " TiB", " PiB", " EiB"],
+                    )
+                };
+                let mut value = float.abs();
+                let mut exponent = 0;
+                while value >= base && exponent < prefixes.len() - 1 {
+                    value /= base;
+                    exponent += 1;
+                }
+                let precision = match self.precision {
+                    Some(p) => p,
+                    None if exponent == 0 && float.fract() == 0.0 => 0,
+                    None => 1,
+                };
+                let (int, frac) = split(value, precision);
+                (int, frac, prefixes[exponent])
+            }
+        };
+        let mut s = String::new();
+        if negative && (int.chars().chain(frac.chars()).any(|c| c != '0')) {
+            s.push('-');
+        }
+        s += &group(&int, self.thousands);
+        if !frac.is_empty() {
+            s.push(self.decimal_point);
+            s += &frac;
+        }
+        s + suffix
+    }
+}
+
+/// Split a non-negative float into its integer and fraction digits.
+fn split(value: f64, precision: usize) -> (String, String) {
+    let text = format!("{:.*}", precision, value);
+    match text.split_once('.') {
+        Some((int, frac)) => (int.to_string(), frac.to_string()),
+        None => (text, String::new()),
+    }
+}
+
+/// Round the digits `int.frac` to `precision` fraction digits, half away from zero.
+fn round_digits(int: &str, frac: &str, precision: usize) -> (String, String) {
+    if frac.len() <= precision {
+        return (
+            int.to_string(),
+            format!("{:0<width$}", frac, width = precision),
+        );
+    }
+    let mut digits = (int.to_string() + &frac[..precision]).into_bytes();
+    if frac.as_bytes()[precision] >= b'5' {
+        match digits.iter().rposition(|&d| d != b'9') {
+            Some(i) => {
+                digits[i] += 1;
+                digits[i + 1..].fill(b'0');
+            }
+            None => {
+                digits.fill(b'0');
+                digits.insert(0, b'1');
+            }
+        }
+    }
+    let digits = String::from_utf8(digits).unwrap_or_default();
+    let (int, frac) = digits.split_at(digits.len() - precision);
+    (int.to_string(), frac.to_string())
+}
+
+fn group(int: &str, separator: Option<char>) -> String {
+    let Some(separator) = separator else {
+        return int.to_string();
+    };
+    let mut s = String::new();
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i).is_multiple_of(3) {
+            s.push(separator);
+        }
+        s.push(c);
+    }
+    s
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+fn format_date(timestamp: i64, pattern: &str) -> String {
+    let (days, seconds) = (timestamp.div_euclid(86400), timestamp.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+    let mut s = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            s.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => s += &year.to_string(),
+            Some('m') => s += &format!("{:02}", month),
+            Some('d') => s += &format!("{:02}", day),
+            Some('H') => s += &format!("{:02}", seconds / 3600),
+            Some('M') => s += &format!("{:02}", seconds / 60 % 60),
+            Some('S') => s += &format!("{:02}", seconds % 60),
+            Some('b') => s += MONTHS[month as usize - 1],
+            Some('%') => s.push('%'),
+            Some(other) => {
+                s.push('%');
+                s.push(other);
+            }
+            None => s.push('%'),
+        }
+    }
+    s
+}
+
+/// Convert days since the Unix epoch to a proleptic Gregorian `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[test]
+fn test_value_format() {
+    let format = ValueFormat::default();
+    assert_eq!(format.format(&Value::Integer(-1234567)), "-1234567");
+    assert_eq!(format.format(&Value::Float(0.1)), "0.1");
+    assert_eq!(format.format(&Value::Decimal(-5, 2)), "-0.05");
+    assert_eq!(format.format(&Value::Bool(true)), "true");
+    assert_eq!(format.format(&Value::Null), "");
+    assert_eq!(format.with_null("n/a").format(&Value::Null), "n/a");
+
+    let format = ValueFormat::default()
+        .with_thousands(Some('.'))
+        .with_decimal_point(',')
+        .with_precision(Some(2));
+    assert_eq!(format.format(&Value::Integer(-1234567)), "-1.234.567,00");
+    assert_eq!(format.format(&Value::Float(1234.567)), "1.234,57");
+    assert_eq!(format.format(&Value::Decimal(99995, 3)), "100,00");
+    assert_eq!(format.format(&Value::Decimal(123456, 3)), "123,46");
+    assert_eq!(format.format(&Value::Decimal(-1, 3)), "0,00");
+
+    let format = ValueFormat::default().with_unit(Unit::Percent);
+    assert_eq!(format.format(&Value::Float(0.256)), "26%");
+    assert_eq!(
+        format
+            .with_precision(Some(1))
+            .format(&Value::Decimal(-256, 3)),
+        "-25.6%"
+    );
+
+    let format = ValueFormat::default().with_unit(Unit::Si);
+    assert_eq!(format.format(&Value::Integer(950)), "950");
+    assert_eq!(format.format(&Value::Integer(1500)), "1.5k");
+    assert_eq!(format.format(&Value::Float(-2_500_000.0)), "-2.5M");
+    let format = format.with_unit(Unit::Bytes);
+    assert_eq!(format.format(&Value::Integer(512)), "512 B");
+    assert_eq!(format.format(&Value::Integer(3 << 30)), "3.0 GiB");
+    let format = format.with_precision(Some(2));
+    assert_eq!(format.format(&Value::Integer(512)), "512.00 B");
+    assert_eq!(format.format(&Value::Integer(1536)), "1.50 KiB");
+    let format = format.with_unit(Unit::Si).with_precision(Some(0));
+    assert_eq!(format.format(&Value::Float(950.4)), "950");
+    assert_eq!(format.format(&Value::Integer(1500)), "2k");
+
+    let format = ValueFormat::default();
+    assert_eq!(format.format(&Value::Timestamp(0)), "1970-01-01 00:00:00");
+    assert_eq!(
+        format.format(&Value::Timestamp(951_827_696)),
+        "2000-02-29 12:34:56"
+    );
+    assert_eq!(format.format(&Value::Timestamp(-1)), "1969-12-31 23:59:59");
+    let format = format.with_date_pattern("%d %b %Y, 100%%");
+    assert_eq!(
+        format.format(&Value::Timestamp(1_700_000_000)),
+        "14 Nov 2023, 100%"
+    );
+
+    assert_eq!(Value::from(Some(3u8)), Value::Integer(3));
+    assert_eq!(Value::from(None::<f64>), Value::Null);
+    assert_eq!(Value::Decimal(150, 2).as_f64(), Some(1.5));
+}
@@ -0,0 +1,41 @@
+//! Bridge between the [fmt::Write] based backends and [io::Write] outputs.
+
+use std::{fmt, io};
+
+use crate::error::TableError;
+
+/// Adapter writing formatted text into an [io::Write], keeping the underlying error.
+struct IoWriter<'w, W: io::Write> {
+    inner: &'w mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// Run a [fmt::Write] based renderer against an [io::Write].
+///
+/// Formatting errors raised by the writer are reported as [TableError::WriteError].
+pub(crate) fn write_io<W: io::Write>(
+    writer: &mut W,
+    render: impl FnOnce(&mut dyn fmt::Write) -> Result<(), TableError>,
+) -> Result<(), TableError> {
+    let mut adapter = IoWriter {
+        inner: writer,
+        error: None,
+    };
+    match render(&mut adapter) {
+        Err(TableError::FormatError(e)) => Err(adapter
+            .error
+            .take()
+            .map(TableError::WriteError)
+            .unwrap_or(TableError::FormatError(e))),
+        r => r,
+    }
+}
@@ -0,0 +1,171 @@
+//! Derive macro for the `TableRow` trait of [table_formatter].
+//!
+//! Use it through the `derive` feature of `table_formatter`, which documents the attributes.
+//!
+//! [table_formatter]: https://docs.rs/table_formatter
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitChar, LitInt, LitStr, Path};
+
+#[proc_macro_derive(TableRow, attributes(table))]
+pub fn derive_table_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The settings of a column, read from the `#[table(...)]` attribute of a field.
+struct Column {
+    title: String,
+    align: Option<TokenStream2>,
+    decimal: bool,
+    separator: Option<LitChar>,
+    width: Option<usize>,
+    overflow: Option<TokenStream2>,
+    formatter: Option<Path>,
+    skip: bool,
+}
+
+impl Column {
+    fn parse(field: &syn::Field, name: String) -> syn::Result<Self> {
+        let mut column = Column {
+            title: name,
+            align: None,
+            decimal: false,
+            separator: None,
+            width: None,
+            overflow: None,
+            formatter: None,
+            skip: false,
+        };
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("table")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("title") {
+                    column.title = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("align") {
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    // The separator of a decimal alignment may come later, so it is built at the end.
+                    column.decimal = lit.value() == "decimal";
+                    column.align = Some(match lit.value().as_str() {
+                        "left" => quote!(::table_formatter::table::Align::Left),
+                        "center" => quote!(::table_formatter::table::Align::Center),
+                        "right" => quote!(::table_formatter::table::Align::Right),
+                        "decimal" => TokenStream2::new(),
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected \"left\", \"center\", \"right\" or \"decimal\"",
+                            ))
+                        }
+                    });
+                } else if meta.path.is_ident("separator") {
+                    column.separator = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("width") {
+                    column.width = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("overflow") {
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    column.overflow = Some(match lit.value().as_str() {
+                        "ellipsis" => quote!(::table_formatter::table::Overflow::Ellipsis),
+                        "hidden" => quote!(::table_formatter::table::Overflow::Hidden),
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected \"ellipsis\" or \"hidden\"",
+                            ))
+                        }
+                    });
+                } else if meta.path.is_ident("formatter") {
+                    column.formatter = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    column.skip = true;
+                } else {
+                    return Err(meta.error("unknown table attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        if column.decimal {
+            let separator = column.separator.as_ref().map_or('.', |s| s.value());
+            column.align = Some(quote!(::table_formatter::table::Align::Decimal(#separator)));
+        } else if let Some(separator) = &column.separator {
+            return Err(syn::Error::new(
+                separator.span(),
+                "`separator` requires `align = \"decimal\"`",
+            ));
+        }
+        Ok(column)
+    }
+
+    /// Builder calls shared by the header and the rows.
+    fn settings(&self) -> TokenStream2 {
+        let mut tokens = quote!();
+        if let Some(align) = &self.align {
+            tokens.extend(quote!(.with_align(#align)));
+        }
+        if let Some(width) = self.width {
+            tokens.extend(quote!(.with_width(::std::option::Option::Some(#width))));
+        }
+        if let Some(overflow) = &self.overflow {
+            tokens.extend(quote!(.with_overflow(#overflow)));
+        }
+        tokens
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "TableRow can only be derived for structs",
+        ));
+    };
+
+    let mut headers = vec![];
+    let mut cells = vec![];
+    for (index, field) in data.fields.iter().enumerate() {
+        let (name, access) = match (&data.fields, &field.ident) {
+            (Fields::Named(_), Some(ident)) => (ident.to_string(), quote!(#ident)),
+            _ => {
+                let index = syn::Index::from(index);
+                (index.index.to_string(), quote!(#index))
+            }
+        };
+        let column = Column::parse(field, name)?;
+        if column.skip {
+            continue;
+        }
+
+        let title = &column.title;
+        let settings = column.settings();
+        headers.push(quote! {
+            ::table_formatter::table::Cell::default()
+                .with_content(::table_formatter::table::Content::new(#title))
+                #settings
+        });
+        let formatter = column.formatter.as_ref().map(|f| {
+            quote!(.with_formatter(::std::vec![::table_formatter::table::FormatterFunc::Normal(#f)]))
+        });
+        cells.push(quote! {
+            ::table_formatter::table::Cell::default()
+                .with_content(::table_formatter::table::Content::new(&self.#access))
+                #settings
+                #formatter
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::table_formatter::table::TableRow for #ident #ty_generics #where_clause {
+            fn header() -> ::std::vec::Vec<::table_formatter::table::Cell> {
+                ::std::vec![#(#headers),*]
+            }
+            fn row(&self) -> ::std::vec::Vec<::table_formatter::table::Cell> {
+                ::std::vec![#(#cells),*]
+            }
+        }
+    })
+}
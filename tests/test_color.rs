@@ -0,0 +1,69 @@
+use table_formatter::{cell, table::*};
+
+// Environment variables are process-wide, so every case lives in a single test.
+#[test]
+fn test_color_policy() {
+    let table = Table::new(vec![vec![cell!(
+        "ok",
+        style = Style::new().foreground(Color::Green)
+    )]])
+    .with_color_policy(ColorPolicy::Always);
+    let colored = "\x1b[32m ok \x1b[0m\n";
+    let plain = " ok \n";
+    assert_eq!(table.to_string_with(Renderer::Normal).unwrap(), colored);
+    assert_eq!(table.to_string_with(Renderer::Raw).unwrap(), plain);
+
+    let table = table.with_color_policy(ColorPolicy::Never);
+    assert_eq!(table.to_string_with(Renderer::Normal).unwrap(), plain);
+
+    for name in ["NO_COLOR", "CLICOLOR", "CLICOLOR_FORCE"] {
+        std::env::remove_var(name);
+    }
+    assert!(ColorPolicy::Auto.should_colorize(true));
+    assert!(!ColorPolicy::Auto.should_colorize(false));
+    // Strings and buffers are never terminals, wherever the standard output goes.
+    let auto = table.clone().with_color_policy(ColorPolicy::Auto);
+    assert_eq!(auto.to_string_with(Renderer::Normal).unwrap(), plain);
+    assert_eq!(auto.to_string(), plain);
+    let mut buffer = vec![];
+    auto.render(&mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), plain);
+    let mut buffer = vec![];
+    TableStream::new(StreamWidths::Sampled(1))
+        .render(auto.rows().map(|r| r.to_vec()), &mut buffer)
+        .unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), plain);
+
+    std::env::set_var("CLICOLOR_FORCE", "1");
+    assert!(ColorPolicy::Auto.should_colorize(false));
+    let table = table.with_color_policy(ColorPolicy::Auto);
+    assert_eq!(table.to_string_with(Renderer::Normal).unwrap(), colored);
+    let mut buffer = vec![];
+    table.render(&mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), colored);
+
+    std::env::set_var("CLICOLOR_FORCE", "0");
+    std::env::set_var("CLICOLOR", "0");
+    assert!(!ColorPolicy::Auto.should_colorize(true));
+    assert!(ColorPolicy::Always.should_colorize(true));
+
+    std::env::remove_var("CLICOLOR");
+    std::env::set_var("CLICOLOR_FORCE", "1");
+    std::env::set_var("NO_COLOR", "1");
+    assert!(!ColorPolicy::Auto.should_colorize(true));
+    assert_eq!(table.to_string_with(Renderer::Normal).unwrap(), plain);
+
+    std::env::set_var("NO_COLOR", "");
+    assert!(ColorPolicy::Auto.should_colorize(false));
+
+    std::env::remove_var("COLORTERM");
+    std::env::set_var("TERM", "xterm-256color");
+    assert_eq!(ColorDepth::from_env(), ColorDepth::Ansi256);
+    std::env::set_var("COLORTERM", "truecolor");
+    assert_eq!(ColorDepth::from_env(), ColorDepth::TrueColor);
+    std::env::remove_var("COLORTERM");
+    std::env::set_var("TERM", "xterm");
+    assert_eq!(ColorDepth::from_env(), ColorDepth::Ansi16);
+    std::env::set_var("TERM", "dumb");
+    assert_eq!(ColorDepth::from_env(), ColorDepth::None);
+}
@@ -127,3 +127,937 @@ fn test_default() {
     table.render(&mut buffer).unwrap();
     println!("{}", String::from_utf8(buffer).unwrap());
 }
+
+#[test]
+fn test_display() {
+    let table = Table::create(
+        vec![cell!("name"), cell!("value", align = Align::Right)],
+        vec![vec![cell!("alpha"), cell!(1, align = Align::Right)]],
+        true,
+    )
+    .with_border(Border::ALL);
+
+    let mut buffer = vec![];
+    table.render_raw(&mut buffer).unwrap();
+    let raw = table.to_string_with(Renderer::Raw).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), raw);
+    assert_eq!(
+        raw,
+        "━━━━━━━━━━━━━━━━\n┃ name   value ┃\n┃━━━━━━━━━━━━━━┃\n┃ alpha      1 ┃\n━━━━━━━━━━━━━━━━\n"
+    );
+
+    let mut buffer = vec![];
+    table.render(&mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), format!("{}", table));
+
+    let ragged = Table::new(vec![vec![cell!("a"), cell!("b")], vec![cell!("c")]]);
+    assert!(ragged.to_string_with(Renderer::Raw).is_err());
+    assert_eq!(
+        ragged.to_string(),
+        "<invalid table: Invalid table size - row 1: find 1 cells, expect 2>"
+    );
+}
+
+#[test]
+fn test_send_sync() {
+    use colored::Colorize;
+    use table_formatter::fmt;
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let prefix = String::from("> ");
+    let table = Table::new(vec![vec![cell!("threaded")
+        .with_formatter(fmt!(Colorize::red, move |s| {
+            format!("{}{}", prefix, s).normal()
+        }))]]);
+    assert_send_sync(&table);
+
+    let rendered = std::thread::spawn(move || table.to_string_with(Renderer::Raw).unwrap())
+        .join()
+        .unwrap();
+    assert_eq!(rendered, " threaded \n");
+}
+
+#[test]
+fn test_style() {
+    let table = Table::new(vec![
+        vec![cell!("name"), cell!("state")],
+        vec![
+            cell!("build", style = Style::new().bold()),
+            cell!(
+                "failed",
+                style = Style::new().foreground(Color::Red).strikethrough()
+            ),
+        ],
+    ]);
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃name┃state┃\n┃:--┃:--┃\n┃**build**┃~~failed~~┃\n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name   state  \n build  failed \n"
+    );
+}
+
+#[test]
+fn test_rules() {
+    let table = Table::create(
+        vec![cell!("-1"), cell!("status")],
+        vec![
+            vec![cell!("-1"), cell!("OK")],
+            vec![cell!("2"), cell!("FAIL")],
+        ],
+        false,
+    )
+    .with_color_policy(ColorPolicy::Always)
+    .with_rule(
+        Rule::new(
+            Predicate::LessThan(0.0),
+            Style::new().foreground(Color::Red),
+        )
+        .on_column(0),
+    )
+    .with_rule(Rule::new(
+        Predicate::In(vec!["OK".to_string(), "FAIL".to_string()]),
+        Style::new().bold(),
+    ))
+    .with_rule(Rule::new(
+        Predicate::Equals("OK".to_string()),
+        Style::new().foreground(Color::Green),
+    ));
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        " -1  status \n\x1b[31m -1 \x1b[0m\x1b[1;32m OK     \x1b[0m\n 2  \x1b[1m FAIL   \x1b[0m\n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃-1┃status┃\n┃:--┃:--┃\n┃-1┃**OK**┃\n┃2┃**FAIL**┃\n"
+    );
+}
+
+#[test]
+fn test_stripes() {
+    use colored::Colorize;
+    use table_formatter::fmt;
+
+    let even = Style::new().background(Color::Black);
+    let odd = Style::new().background(Color::White);
+    let table = Table::create(
+        vec![cell!("h")],
+        vec![
+            vec![cell!("a")],
+            vec![cell!("b")],
+            vec![cell!("c", style = Style::new().background(Color::Red))],
+            vec![cell!("d").with_formatter(fmt!(Colorize::underline))],
+        ],
+        true,
+    )
+    .with_color_policy(ColorPolicy::Always)
+    .with_stripes(vec![even, odd]);
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        "\x1b[1m h \x1b[0m\n\x1b[1m━━━\x1b[0m\n\x1b[40m a \x1b[0m\n\x1b[47m b \x1b[0m\n\x1b[41m c \x1b[0m\n\x1b[4;47m d \x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_color_scale() {
+    let table = Table::create(
+        vec![cell!("ms")],
+        vec![
+            vec![cell!(10)],
+            vec![cell!(15)],
+            vec![cell!(20)],
+            vec![cell!("n/a")],
+            vec![cell!("NaN")],
+        ],
+        false,
+    )
+    .with_color_policy(ColorPolicy::Always)
+    .with_color_scale(ColorScale::two(
+        0,
+        Color::Rgb(0, 255, 0),
+        Color::Rgb(255, 0, 0),
+    ));
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        " ms  \n\x1b[48;2;0;255;0m 10  \x1b[0m\n\x1b[48;2;128;128;0m 15  \x1b[0m\n\x1b[48;2;255;0;0m 20  \x1b[0m\n n/a \n NaN \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " ms  \n 10  \n 15  \n 20  \n n/a \n NaN \n"
+    );
+}
+
+#[test]
+fn test_color_depth() {
+    let table = Table::create(
+        vec![cell!("ms")],
+        vec![vec![cell!(10)], vec![cell!(20)]],
+        false,
+    )
+    .with_color_policy(ColorPolicy::Always)
+    .with_color_scale(ColorScale::two(
+        0,
+        Color::Rgb(0, 255, 0),
+        Color::Rgb(255, 0, 0),
+    ));
+    assert_eq!(
+        table
+            .clone()
+            .with_color_depth(ColorDepth::Ansi256)
+            .to_string_with(Renderer::Normal)
+            .unwrap(),
+        " ms \n\x1b[48;5;46m 10 \x1b[0m\n\x1b[48;5;196m 20 \x1b[0m\n"
+    );
+    assert_eq!(
+        table
+            .clone()
+            .with_color_depth(ColorDepth::Ansi16)
+            .to_string_with(Renderer::Normal)
+            .unwrap(),
+        " ms \n\x1b[102m 10 \x1b[0m\n\x1b[101m 20 \x1b[0m\n"
+    );
+    assert_eq!(
+        table
+            .with_color_depth(ColorDepth::None)
+            .to_string_with(Renderer::Normal)
+            .unwrap(),
+        " ms \n 10 \n 20 \n"
+    );
+}
+
+#[test]
+fn test_link() {
+    let table = Table::new(vec![vec![
+        cell!("#42", style = Style::new().bold())
+            .with_link(Some("https://example.com/42".to_string())),
+        cell!("open"),
+    ]])
+    .with_color_policy(ColorPolicy::Always);
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        "\x1b[1m \x1b[0m\x1b]8;;https://example.com/42\x1b\\\x1b[1m#42\x1b[0m\x1b]8;;\x1b\\\x1b[1m \x1b[0m open \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " #42  open \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃[**#42**](https://example.com/42)┃open┃\n┃:--┃:--┃\n"
+    );
+
+    let table = Table::new(vec![vec![
+        cell!("[draft]").with_link(Some("https://example.com/a b_(1)".to_string()))
+    ]]);
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃[\\[draft\\]](<https://example.com/a b_(1)>)┃\n┃:--┃\n"
+    );
+}
+
+#[test]
+fn test_spans() {
+    let spans = vec![
+        Span::new("serde", Style::new().bold()),
+        Span::new(" (optional)", Style::new().dim()),
+    ];
+    let table = Table::new(vec![
+        vec![cell!("crate")],
+        vec![Cell::default()
+            .with_content(Content::Spans(spans))
+            .with_style_scope(StyleScope::Content)],
+    ])
+    .with_color_policy(ColorPolicy::Always);
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        " crate            \n \x1b[1mserde\x1b[0m\x1b[2m (optional)\x1b[0m \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃crate┃\n┃:--┃\n┃**serde** (optional)┃\n"
+    );
+
+    let table = Table::new(vec![vec![Cell::default()
+        .with_content(Content::Spans(vec![
+            Span::new("serde", Style::new().bold()),
+            Span::new(" (optional)", Style::new().dim()),
+        ]))
+        .with_width(Some(8))
+        .with_style_scope(StyleScope::Content)]])
+    .with_color_policy(ColorPolicy::Always);
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        " \x1b[1mserde\x1b[0m\x1b[2m...\x1b[0m \n"
+    );
+    assert_eq!(table.to_string_with(Renderer::Raw).unwrap(), " serde... \n");
+}
+
+#[test]
+fn test_values() {
+    let money = ValueFormat::default()
+        .with_precision(Some(2))
+        .with_thousands(Some(','));
+    let row = |name: &str, amount: Value, paid: Option<i64>| {
+        vec![
+            cell!(name),
+            Cell::default().with_content(Content::Value(amount, money.clone())),
+            Cell::default().with_content(Content::Value(
+                paid.map_or(Value::Null, Value::Timestamp),
+                ValueFormat::default()
+                    .with_date_pattern("%Y-%m-%d")
+                    .with_null("-"),
+            )),
+        ]
+    };
+    let table = Table::create(
+        vec![cell!("name"), cell!("amount"), cell!("paid")],
+        vec![
+            row("a", Value::Decimal(123456, 2), Some(1_700_000_000)),
+            row("b", Value::Integer(-5), None),
+        ],
+        false,
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name  amount    paid       \n a     1,234.56  2023-11-14 \n b        -5.00  -          \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃name┃amount┃paid┃\n┃:--┃:--┃:--┃\n┃a┃1,234.56┃2023-11-14┃\n┃b┃-5.00┃-┃\n"
+    );
+    let table = table
+        .with_color_policy(ColorPolicy::Always)
+        .with_rule(Rule::new(Predicate::LessThan(0.0), Style::new().bold()));
+    assert_eq!(
+        table.to_string_with(Renderer::Normal).unwrap(),
+        " name  amount    paid       \n a     1,234.56  2023-11-14 \n b    \x1b[1m    -5.00 \x1b[0m -          \n"
+    );
+}
+
+#[test]
+fn test_decimal_align() {
+    let amount = |s: &str| cell!(s, align = Align::Decimal('.'));
+    let table = Table::create(
+        vec![cell!("item"), cell!("amount", align = Align::Right)],
+        vec![
+            vec![cell!("a"), amount("3.5")],
+            vec![cell!("b"), amount("120.25")],
+            vec![cell!("c"), amount("7")],
+            vec![cell!("d"), amount("1234")],
+        ],
+        false,
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " item   amount \n a        3.5  \n b      120.25 \n c        7    \n d     1234    \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃item┃amount┃\n┃:--┃--:┃\n┃a┃3.5┃\n┃b┃120.25┃\n┃c┃7┃\n┃d┃1234┃\n"
+    );
+
+    let comma = Align::Decimal(',');
+    let table = Table::new(vec![
+        vec![cell!("1,5", align = comma)],
+        vec![cell!("10", align = comma)],
+    ]);
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        "  1,5 \n 10   \n"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_from_serialize() {
+    #[derive(serde::Serialize)]
+    struct Build {
+        name: &'static str,
+        ok: bool,
+        duration: Option<f64>,
+        tags: Vec<&'static str>,
+    }
+
+    let builds = [
+        Build {
+            name: "lint",
+            ok: true,
+            duration: Some(1.5),
+            tags: vec!["ci"],
+        },
+        Build {
+            name: "deploy",
+            ok: false,
+            duration: None,
+            tags: vec![],
+        },
+    ];
+    let fields = Fields::new()
+        .with_order(vec!["ok".to_string(), "missing".to_string()])
+        .with_rename("duration", "secs");
+    let table = Table::from_serialize(&builds, &fields).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃ok┃name┃secs┃tags┃\n┃:--┃:--┃:--┃:--┃\n┃━━━┃━━━┃━━━┃━━━┃\n┃true┃lint┃1.5┃ci┃\n┃false┃deploy┃┃┃\n"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_table_row() {
+    use colored::{ColoredString, Colorize};
+
+    fn loud(s: ColoredString) -> ColoredString {
+        s.to_uppercase().normal()
+    }
+
+    #[derive(TableRow)]
+    struct Service {
+        #[table(title = "Service", formatter = loud)]
+        name: &'static str,
+        #[table(align = "center", width = 7)]
+        state: String,
+        #[table(skip)]
+        #[allow(dead_code)]
+        pid: u32,
+        #[table(title = "Note", width = 5, overflow = "ellipsis")]
+        note: &'static str,
+    }
+
+    let services = vec![
+        Service {
+            name: "db",
+            state: "up".to_string(),
+            pid: 1,
+            note: "primary node",
+        },
+        Service {
+            name: "cache",
+            state: "down".to_string(),
+            pid: 2,
+            note: "-",
+        },
+    ];
+    let table = Table::from_rows(&services).with_color_policy(ColorPolicy::Always);
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " Service   state   Note  \n━━━━━━━━━━━━━━━━━━━━━━━━━\n db         up     pr... \n cache     down    -     \n"
+    );
+    assert!(table
+        .to_string_with(Renderer::Normal)
+        .unwrap()
+        .contains(" CACHE   "));
+
+    #[derive(TableRow)]
+    struct Pair(u8, #[table(title = "second")] char);
+    assert_eq!(
+        Table::from_rows(&[Pair(1, 'x')])
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        " 0  second \n━━━━━━━━━━━\n 1  x      \n"
+    );
+
+    #[derive(TableRow)]
+    struct Price {
+        #[table(align = "decimal")]
+        usd: f64,
+        #[table(align = "decimal", separator = ',')]
+        eur: &'static str,
+    }
+    let prices = [
+        Price {
+            usd: 12.5,
+            eur: "11,25",
+        },
+        Price {
+            usd: 100.0,
+            eur: "92",
+        },
+    ];
+    assert_eq!(
+        Table::from_rows(&prices)
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        " usd    eur    \n━━━━━━━━━━━━━━━\n  12.5   11,25 \n 100     92    \n"
+    );
+}
+
+#[test]
+fn test_from_csv() {
+    let tsv = "id\tnote\n1\t\"tab\there\"\n2\t\n";
+    let table = Table::from_tsv(tsv.as_bytes(), false).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " id  note     \n 1   tab\there \n 2            \n"
+    );
+    assert_eq!(
+        Table::from_csv("".as_bytes(), true)
+            .unwrap()
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        ""
+    );
+    let Err(err) = Table::from_csv("a,b\n1,2,3\n".as_bytes(), true) else {
+        panic!("expected an error");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid record length - line 2: find 3 fields, expect 2"
+    );
+    assert!(matches!(
+        Table::from_csv(&[0xff, 0xfe][..], true),
+        Err(table_formatter::error::TableError::ReadError(_))
+    ));
+    let table = Table::from_csv(",x\n,y\n".as_bytes(), false).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        "   x \n   y \n"
+    );
+    let csv = "name,note\nlint,\"first\r\nsecond\nthird\"\n";
+    let table = Table::from_csv(csv.as_bytes(), true).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name  note               \n━━━━━━━━━━━━━━━━━━━━━━━━━━\n lint  first second third \n"
+    );
+}
+
+#[test]
+fn test_parse_rendered() {
+    let table = Table::create(
+        vec![
+            Cell::default().with_content(Content::new("name")),
+            Cell::default()
+                .with_content(Content::new("state"))
+                .with_align(Align::Center),
+            Cell::default()
+                .with_content(Content::new("count"))
+                .with_align(Align::Right),
+        ],
+        vec![
+            vec![
+                Cell::default().with_content(Content::new("build")),
+                Cell::default()
+                    .with_content(Content::new("ok"))
+                    .with_align(Align::Center),
+                Cell::default()
+                    .with_content(Content::new("12"))
+                    .with_align(Align::Right),
+            ],
+            vec![
+                Cell::default().with_content(Content::new("lint a")),
+                Cell::default()
+                    .with_content(Content::new("failed"))
+                    .with_align(Align::Center),
+                Cell::default()
+                    .with_content(Content::new("3"))
+                    .with_align(Align::Right),
+            ],
+        ],
+        true,
+    )
+    .with_border(Border::ALL);
+    let rendered = table.to_string_with(Renderer::Raw).unwrap();
+    let parsed = Table::from_box(&rendered).unwrap();
+    assert_eq!(parsed.to_string_with(Renderer::Raw).unwrap(), rendered);
+
+    let markdown = table.to_string_with(Renderer::Markdown).unwrap();
+    let parsed = Table::from_markdown(&markdown).unwrap();
+    assert_eq!(parsed.to_string_with(Renderer::Markdown).unwrap(), markdown);
+    assert_eq!(
+        parsed.to_string_with(Renderer::Raw).unwrap(),
+        " name    state   count \n━━━━━━━━━━━━━━━━━━━━━━━\n build     ok       12 \n lint a  failed      3 \n"
+    );
+
+    let mut merged = cell!("all jobs").with_span(1);
+    merged.push(cell!("15"));
+    let table = Table::create(
+        vec![cell!("name"), cell!("state"), cell!("count")],
+        vec![vec![cell!("build"), cell!("ok"), cell!("12")], merged],
+        true,
+    );
+    let rendered = table.to_string_with(Renderer::Raw).unwrap();
+    let parsed = Table::from_box(&rendered).unwrap();
+    assert_eq!(parsed.column_count(), 3);
+    assert_eq!(parsed.get_cell(0, 1).unwrap().get_merge(), Some(1));
+    assert_eq!(parsed.to_string_with(Renderer::Raw).unwrap(), rendered);
+
+    let blank = || vec![vec![cell!(""), cell!("a"), cell!(""), cell!("c"), cell!("")]; 2];
+    let rendered = Table::new(blank()).to_string_with(Renderer::Raw).unwrap();
+    let parsed = Table::from_box(&rendered).unwrap();
+    assert_eq!(parsed.column_count(), 5);
+    assert_eq!(parsed.to_string_with(Renderer::Raw).unwrap(), rendered);
+    let table = Table::new(blank()).with_border(Border::ALL);
+    let rendered = table.to_string_with(Renderer::Raw).unwrap();
+    let parsed = Table::from_box(&rendered).unwrap();
+    assert_eq!(parsed.column_count(), 5);
+    assert_eq!(parsed.to_string_with(Renderer::Raw).unwrap(), rendered);
+
+    let parsed = Table::from_markdown("a | b\n--|--\nx |\n").unwrap();
+    assert_eq!(
+        parsed.to_string_with(Renderer::Raw).unwrap(),
+        " a  b \n━━━━━━\n x    \n"
+    );
+
+    let Err(err) = Table::from_markdown("| a | b |\n| -- | x |\n") else {
+        panic!("expected an error");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid table text - line 2: invalid delimiter row"
+    );
+    assert!(matches!(
+        Table::from_markdown("a | b\n--|--\n1 | 2 | 3\n"),
+        Err(table_formatter::error::TableError::InvalidRecordLength {
+            line: 3,
+            expected: 2,
+            actual: 3
+        })
+    ));
+    let Err(err) = Table::from_box("| a | b |\n  c | d |\n") else {
+        panic!("expected an error");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid table text - line 2: inconsistent border"
+    );
+}
+
+#[test]
+fn test_edit_table() {
+    use table_formatter::error::TableError;
+
+    let mut table = Table::create(
+        vec![cell!("name"), cell!("size")],
+        vec![vec![cell!("a"), cell!("1")], vec![cell!("b"), cell!("2")]],
+        true,
+    );
+    assert_eq!((table.row_count(), table.column_count()), (2, 2));
+    table.push_row(vec![cell!("c"), cell!("3")]).unwrap();
+    table
+        .insert_row(0, cell!("all", align = Align::Center).with_span(1))
+        .unwrap();
+    let removed = table.remove_row(2).unwrap();
+    assert_eq!(removed[0].get_content().get_text().unwrap(), "b");
+    table
+        .push_column(
+            Some(cell!("kind")),
+            vec![cell!("-"), cell!("x"), cell!("y")],
+        )
+        .unwrap();
+    assert_eq!(
+        table
+            .get_cell(2, 1)
+            .unwrap()
+            .get_content()
+            .get_text()
+            .unwrap(),
+        "x"
+    );
+    assert_eq!(
+        table.rows().map(|r| r.len()).collect::<Vec<_>>(),
+        vec![3, 3, 3]
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name  size  kind \n━━━━━━━━━━━━━━━━━━\n    all      -    \n a     1     x    \n c     3     y    \n"
+    );
+
+    assert!(matches!(
+        table.get_cell(0, 3),
+        Err(TableError::RowOutOfTable { row: 3, rows: 3 })
+    ));
+    assert!(matches!(
+        table.remove_column(3),
+        Err(TableError::ColumnOutOfTable {
+            column: 3,
+            columns: 3
+        })
+    ));
+    assert!(matches!(
+        table.push_row(vec![cell!("d")]),
+        Err(TableError::InvalidTableSize { .. })
+    ));
+    assert!(matches!(
+        table.push_column(None, vec![cell!("d")]),
+        Err(TableError::InvalidColumnSize {
+            column: 3,
+            expected: 3,
+            actual: 1
+        })
+    ));
+    assert!(matches!(
+        Table::new(vec![vec![cell!("a")]]).push_column(Some(cell!("b")), vec![cell!("c")]),
+        Err(TableError::NoHeader { column: 1 })
+    ));
+    let mut ragged = Table::new(vec![vec![cell!("a"), cell!("b")], vec![cell!("c")]]);
+    assert!(matches!(
+        ragged.get_cell(1, 1),
+        Err(TableError::ColumnOutOfTable {
+            column: 1,
+            columns: 1
+        })
+    ));
+    assert!(matches!(
+        ragged.set_cell(1, 1, cell!("d")),
+        Err(TableError::ColumnOutOfTable {
+            column: 1,
+            columns: 1
+        })
+    ));
+    assert!(matches!(
+        table.insert_column(1, None, vec![cell!("d"), cell!("e"), cell!("f")]),
+        Err(TableError::MergeSplit { x: 1, y: 2 })
+    ));
+    assert!(matches!(
+        table.remove_column(0),
+        Err(TableError::MergeSplit { x: 0, y: 2 })
+    ));
+    assert!(matches!(
+        table.set_cell(1, 0, cell!("z")),
+        Err(TableError::MergeSplit { x: 1, y: 2 })
+    ));
+    assert!(matches!(
+        table.set_cell(1, 1, cell!("z").with_merge(Some(1))),
+        Err(TableError::MergeOverwrite { x: 2, y: 3 })
+    ));
+    assert_eq!(table.remove_column(2).unwrap().len(), 3);
+    table
+        .insert_column(2, None, vec![cell!("d"), cell!("e"), cell!("f")])
+        .unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name  size    \n━━━━━━━━━━━━━━━\n    all      d \n a     1     e \n c     3     f \n"
+    );
+}
+
+#[test]
+fn test_sort_by_columns() {
+    use std::sync::Arc;
+    use table_formatter::error::TableError;
+
+    let rows = vec![
+        vec![cell!("worker-10"), cell!("idle")],
+        vec![cell!("worker-2"), cell!("busy")],
+        vec![cell!("worker-1"), cell!("down")],
+    ];
+    let mut table = Table::create(vec![cell!("name"), cell!("state")], rows, true);
+    table.sort_by_columns(&[SortKey::new(0)]).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " name       state \n━━━━━━━━━━━━━━━━━━\n worker-1   down  \n worker-2   busy  \n worker-10  idle  \n"
+    );
+
+    let rank = |c: &Content| match c.get_text().as_deref() {
+        Some("down") => 0,
+        Some("busy") => 1,
+        _ => 2,
+    };
+    table
+        .sort_by_columns(&[SortKey::new(1)
+            .with_order(SortOrder::Descending)
+            .with_compare(Compare::Custom(Arc::new(move |a, b| rank(a).cmp(&rank(b)))))])
+        .unwrap();
+    assert_eq!(
+        table
+            .rows()
+            .map(|r| r[1].get_content().get_text().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["idle", "busy", "down"]
+    );
+
+    table
+        .push_row(cell!("total", align = Align::Center).with_span(1))
+        .unwrap();
+    table.sort_by_columns(&[SortKey::new(0)]).unwrap();
+    assert!(matches!(
+        table.sort_by_columns(&[SortKey::new(1)]),
+        Err(TableError::SortOnMerged { x: 1, y: 2 })
+    ));
+    assert!(matches!(
+        table.sort_by_columns(&[SortKey::new(2)]),
+        Err(TableError::ColumnOutOfTable {
+            column: 2,
+            columns: 2
+        })
+    ));
+    let splitter = || vec![Cell::default().with_content(Content::Splitter)];
+    let rows = vec![
+        vec![cell!("b")],
+        splitter(),
+        vec![cell!("d")],
+        vec![cell!("a")],
+        vec![cell!("c")],
+    ];
+    let mut table = Table::create(vec![cell!("id")], rows, true);
+    table.sort_by_columns(&[SortKey::new(0)]).unwrap();
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " id \n━━━━\n b  \n━━━━\n a  \n c  \n d  \n"
+    );
+
+    let mut ragged = Table::new(vec![vec![cell!("b"), cell!("1")], vec![cell!("a")]]);
+    assert!(matches!(
+        ragged.sort_by_columns(&[SortKey::new(1)]),
+        Err(TableError::InvalidTableSize {
+            row: 1,
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
+
+#[test]
+fn test_paginate() {
+    use table_formatter::error::TableError;
+
+    let rows = ["a", "bb", "ccc", "dddd", "eeeee"]
+        .iter()
+        .map(|s| vec![cell!(s)])
+        .collect();
+    let table = Table::create(vec![cell!("id")], rows, true).with_border(Border::ALL);
+    let stable = Pagination::new(2).with_footer(true);
+    assert_eq!(table.page_count(&stable), 3);
+    assert_eq!(
+        table
+            .page(0, &stable)
+            .unwrap()
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        "━━━━━━━━━\n┃ id    ┃\n┃━━━━━━━┃\n┃ a     ┃\n┃ bb    ┃\n━━━━━━━━━\nrows 1–2 of 5\n"
+    );
+    let per_page = Pagination::new(2).with_widths(PageWidths::PerPage);
+    assert_eq!(
+        table
+            .page(2, &per_page)
+            .unwrap()
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        "━━━━━━━━━\n┃ id    ┃\n┃━━━━━━━┃\n┃ eeeee ┃\n━━━━━━━━━\n"
+    );
+    assert_eq!(
+        table
+            .page(0, &per_page)
+            .unwrap()
+            .to_string_with(Renderer::Raw)
+            .unwrap(),
+        "━━━━━━\n┃ id ┃\n┃━━━━┃\n┃ a  ┃\n┃ bb ┃\n━━━━━━\n"
+    );
+    assert!(matches!(
+        table.page(3, &stable),
+        Err(TableError::PageOutOfTable { page: 3, pages: 3 })
+    ));
+
+    let slice = table.slice_rows(1..=2).unwrap().with_caption("2 shown");
+    assert_eq!(
+        slice.to_string_with(Renderer::Markdown).unwrap(),
+        "┃id┃\n┃:--┃\n┃━━━┃\n┃bb┃\n┃ccc┃\n\n2 shown\n"
+    );
+    assert!(matches!(
+        table.slice_rows(4..6),
+        Err(TableError::RowOutOfTable { row: 6, rows: 5 })
+    ));
+    assert!(matches!(
+        table.slice_rows(..=usize::MAX),
+        Err(TableError::RowOutOfTable {
+            row: usize::MAX,
+            rows: 5
+        })
+    ));
+    assert!(matches!(
+        table.slice_rows((
+            std::ops::Bound::Excluded(usize::MAX),
+            std::ops::Bound::Unbounded
+        )),
+        Err(TableError::RowOutOfTable {
+            row: usize::MAX,
+            rows: 5
+        })
+    ));
+    let captioned = table.clone().with_caption("builds");
+    assert_eq!(
+        captioned.page(2, &stable).unwrap().get_caption(),
+        Some("builds\nrows 5–5 of 5")
+    );
+    let long = table.filter_rows(|row| row[0].get_content().get_text().unwrap().len() > 3);
+    assert_eq!(long.row_count(), 2);
+    let empty = table.filter_rows(|_| false);
+    assert_eq!(
+        empty.page(0, &stable).unwrap().get_caption(),
+        Some("rows 0 of 0")
+    );
+}
+
+#[test]
+fn test_footer_aggregates() {
+    use std::sync::Arc;
+
+    let ms = |v: f64| {
+        Cell::default()
+            .with_content(Content::Value(
+                v.into(),
+                ValueFormat::default().with_precision(Some(1)),
+            ))
+            .with_align(Align::Decimal('.'))
+    };
+    let table = Table::create(
+        vec![cell!("bench"), cell!("ms"), cell!("note")],
+        vec![
+            vec![cell!("parse"), ms(12.25), cell!("-")],
+            vec![cell!("render"), ms(3.5), cell!("7")],
+            vec![cell!("write"), ms(100.0), cell!("x")],
+        ],
+        true,
+    )
+    .with_footer(
+        Footer::new()
+            .with_label(0, "mean")
+            .with_aggregate(1, Aggregate::Mean)
+            .with_aggregate(2, Aggregate::Count),
+    )
+    .with_footer(
+        Footer::new()
+            .with_splitter(false)
+            .with_label(0, "spread")
+            .with_aggregate(
+                1,
+                Aggregate::Custom(Arc::new(|v: &[f64]| {
+                    let max = v.iter().copied().reduce(f64::max)?;
+                    Some(max - v.iter().copied().reduce(f64::min)?)
+                })),
+            )
+            .with_cell(2, cell!("-")),
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Raw).unwrap(),
+        " bench   ms     note \n━━━━━━━━━━━━━━━━━━━━━\n parse    12.2  -    \n render    3.5  7    \n write   100.0  x    \n━━━━━━━━━━━━━━━━━━━━━\n mean     38.6     1 \n spread   96.5  -    \n"
+    );
+    assert_eq!(
+        table.to_string_with(Renderer::Markdown).unwrap(),
+        "┃bench┃ms┃note┃\n┃:--┃:--┃:--┃\n┃━━━┃━━━┃━━━┃\n┃parse┃12.2┃-┃\n┃render┃3.5┃7┃\n┃write┃100.0┃x┃\n┃━━━┃━━━┃━━━┃\n┃**mean**┃**38.6**┃**1**┃\n┃**spread**┃**96.5**┃**-**┃\n"
+    );
+
+    let page = table.page(1, &Pagination::new(2)).unwrap();
+    assert_eq!(
+        page.to_string_with(Renderer::Raw).unwrap(),
+        " bench   ms     note \n━━━━━━━━━━━━━━━━━━━━━\n write   100.0  x    \n━━━━━━━━━━━━━━━━━━━━━\n mean    100.0     0 \n spread    0.0  -    \n"
+    );
+    let empty = table
+        .filter_rows(|_| false)
+        .with_footer(Footer::new().with_aggregate(1, Aggregate::Sum));
+    assert!(empty
+        .to_string_with(Renderer::Raw)
+        .unwrap()
+        .ends_with("        0       \n"));
+    assert!(matches!(
+        Table::new(vec![vec![cell!("a")]])
+            .with_footer(Footer::new().with_aggregate(1, Aggregate::Max))
+            .to_string_with(Renderer::Raw),
+        Err(table_formatter::error::TableError::ColumnOutOfTable {
+            column: 1,
+            columns: 1
+        })
+    ));
+}